@@ -17,6 +17,14 @@ export const TAX_AUTHORITY = new PublicKey(
     process.env.NEXT_PUBLIC_TAX_AUTHORITY_WALLET || '11111111111111111111111111111111'
 );
 
+// Tax Authority's ShadowWire confidential account (receives withheld tax)
+export const TAX_CONFIDENTIAL_ACCOUNT = new PublicKey(
+    process.env.NEXT_PUBLIC_TAX_CONFIDENTIAL_ACCOUNT || '11111111111111111111111111111111'
+);
+
+// Payroll ID - a creator can run several payrolls, told apart by ID
+export const PAYROLL_ID = BigInt(process.env.NEXT_PUBLIC_PAYROLL_ID || '0');
+
 // Create connection
 export const getConnection = () => {
     const endpoint = process.env.NEXT_PUBLIC_RPC_URL || 'https://api.devnet.solana.com';
@@ -29,9 +37,22 @@ export const getProgram = (provider: AnchorProvider) => {
 };
 
 // PDA derivation helpers
-export const getPayrollPDA = (authority: PublicKey): [PublicKey, number] => {
+export const getPayrollPDA = (
+    creator: PublicKey,
+    payrollId: bigint = PAYROLL_ID
+): [PublicKey, number] => {
+    const payrollIdBuffer = Buffer.alloc(8);
+    payrollIdBuffer.writeBigUInt64LE(payrollId);
+
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('payroll'), creator.toBuffer(), payrollIdBuffer],
+        PROGRAM_ID
+    );
+};
+
+export const getRoleRegistryPDA = (payroll: PublicKey): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
-        [Buffer.from('payroll'), authority.toBuffer()],
+        [Buffer.from('roles'), payroll.toBuffer()],
         PROGRAM_ID
     );
 };
@@ -46,21 +67,61 @@ export const getEmployeePDA = (
     );
 };
 
+export const getInvitePDA = (
+    payroll: PublicKey,
+    employeeId: string
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('invite'), payroll.toBuffer(), Buffer.from(employeeId)],
+        PROGRAM_ID
+    );
+};
+
+export const getScreeningHistoryPDA = (employee: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('screening_history'), employee.toBuffer()],
+        PROGRAM_ID
+    );
+};
+
+export const getSalaryHistoryPDA = (employee: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('salary_history'), employee.toBuffer()],
+        PROGRAM_ID
+    );
+};
+
+export const getScreeningProviderPDA = (
+    payroll: PublicKey,
+    provider: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('screening_provider'), payroll.toBuffer(), provider.toBuffer()],
+        PROGRAM_ID
+    );
+};
+
+// `index` is 0 for a regular payment, or the off-cycle payment's index
 export const getPaymentRecordPDA = (
     employee: PublicKey,
-    timestamp: number
+    period: bigint,
+    index: number = 0
 ): [PublicKey, number] => {
-    const timestampBuffer = Buffer.alloc(8);
-    timestampBuffer.writeBigInt64LE(BigInt(timestamp));
+    const periodBuffer = Buffer.alloc(8);
+    periodBuffer.writeBigUInt64LE(period);
+    const indexBuffer = Buffer.alloc(4);
+    indexBuffer.writeUInt32LE(index);
 
     return PublicKey.findProgramAddressSync(
-        [Buffer.from('payment'), employee.toBuffer(), timestampBuffer],
+        [Buffer.from('payment'), employee.toBuffer(), periodBuffer, indexBuffer],
         PROGRAM_ID
     );
 };
 
 // Account types (matching on-chain structs)
 export interface Payroll {
+    creator: PublicKey;
+    payrollId: bigint;
     authority: PublicKey;
     taxAuthority: PublicKey;
     taxRateBps: number;
@@ -1,13 +1,26 @@
 'use client';
 
-import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
-import { Program, AnchorProvider } from '@coral-xyz/anchor';
 import {
+    Connection,
+    PublicKey,
+    SystemProgram,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    TransactionInstruction,
+} from '@solana/web3.js';
+import { BN, Program, AnchorProvider } from '@coral-xyz/anchor';
+import {
+    PAYROLL_ID,
     PROGRAM_ID,
     SHADOWWIRE_PROGRAM_ID,
     TAX_AUTHORITY,
+    TAX_CONFIDENTIAL_ACCOUNT,
     getPayrollPDA,
+    getRoleRegistryPDA,
     getEmployeePDA,
+    getInvitePDA,
+    getScreeningHistoryPDA,
+    getSalaryHistoryPDA,
+    getScreeningProviderPDA,
     createSalaryCommitment
 } from './anchor-client';
 import idl from './idl.json';
@@ -135,26 +148,33 @@ export async function isPayrollInitialized(
  */
 export async function initializePayrollOnChain(
     provider: AnchorProvider,
-    taxRateBps: number = 500 // 5%
+    taxRateBps: number = 500, // 5%
+    configDelaySeconds: number = 0,
+    payrollId: bigint = PAYROLL_ID
 ): Promise<string> {
     const program = new Program(idl as Idl, provider);
     const authority = provider.wallet.publicKey;
-    const [payrollPDA] = getPayrollPDA(authority);
+    const [payrollPDA] = getPayrollPDA(authority, payrollId);
+    const [roleRegistryPDA] = getRoleRegistryPDA(payrollPDA);
 
     console.log('Initializing payroll with:');
     console.log('  Payroll PDA:', payrollPDA.toBase58());
+    console.log('  Payroll ID:', payrollId.toString());
     console.log('  Authority:', authority.toBase58());
     console.log('  Tax Authority:', TAX_AUTHORITY.toBase58());
+    console.log('  Tax Confidential Account:', TAX_CONFIDENTIAL_ACCOUNT.toBase58());
     console.log('  ShadowWire Program:', SHADOWWIRE_PROGRAM_ID.toBase58());
     console.log('  Tax Rate BPS:', taxRateBps);
 
     const tx = await (program.methods as any)
-        .initialize(taxRateBps)
+        .initialize(new BN(payrollId.toString()), taxRateBps, new BN(configDelaySeconds))
         .accounts({
             payroll: payrollPDA,
+            roleRegistry: roleRegistryPDA,
             authority: authority,
             taxAuthority: TAX_AUTHORITY,
             shadowwireProgram: SHADOWWIRE_PROGRAM_ID,
+            taxConfidentialAccount: TAX_CONFIDENTIAL_ACCOUNT,
             systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -164,55 +184,114 @@ export async function initializePayrollOnChain(
 }
 
 /**
- * Add employee on-chain
+ * Screening attestation signed by a registered screening provider
+ */
+export interface ScreeningAttestation {
+    provider: PublicKey;
+    screenedAt: number;
+    // Ed25519 program instruction carrying the provider's signature
+    ed25519Instruction: TransactionInstruction;
+}
+
+/**
+ * Invite an employee on-chain (payroll authority or HR admin)
+ * The employee becomes active once they accept with acceptEmployeeInviteOnChain
  */
-export async function addEmployeeOnChain(
+export async function createEmployeeInviteOnChain(
     provider: AnchorProvider,
     employeeData: {
         id: string;
         name: string;
         wallet: string;
         salary: number;
-        screeningScore: number;
-    }
+    },
+    payrollId: bigint = PAYROLL_ID
 ): Promise<string> {
     const program = new Program(idl as Idl, provider);
-    const authority = provider.wallet.publicKey;
+    const hrAdmin = provider.wallet.publicKey;
 
-    const [payrollPDA] = getPayrollPDA(authority);
-    const [employeePDA] = getEmployeePDA(payrollPDA, employeeData.id);
+    const [payrollPDA] = getPayrollPDA(hrAdmin, payrollId);
+    const [roleRegistryPDA] = getRoleRegistryPDA(payrollPDA);
+    const [invitePDA] = getInvitePDA(payrollPDA, employeeData.id);
 
     // Create salary commitment
     const salaryCommitment = createSalaryCommitment(employeeData.salary);
 
-    // For confidential account, we'll use the employee wallet as placeholder
-    // In production, this would be a ShadowWire confidential account
-    const employeeWallet = new PublicKey(employeeData.wallet);
-
-    console.log('Adding employee on-chain:');
+    console.log('Inviting employee on-chain:');
     console.log('  ID:', employeeData.id);
     console.log('  Name:', employeeData.name);
     console.log('  Wallet:', employeeData.wallet);
-    console.log('  Score:', employeeData.screeningScore);
 
     const tx = await (program.methods as any)
-        .addEmployee(
+        .createEmployeeInvite(
             employeeData.id,
             employeeData.name,
-            Buffer.from(salaryCommitment),
-            employeeData.screeningScore
+            new PublicKey(employeeData.wallet),
+            Buffer.from(salaryCommitment)
         )
         .accounts({
             payroll: payrollPDA,
+            roleRegistry: roleRegistryPDA,
+            invite: invitePDA,
+            hrAdmin: hrAdmin,
+            systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    console.log('Employee invited! TX:', tx);
+    return tx;
+}
+
+/**
+ * Accept an employee invite on-chain (invited employee wallet only)
+ */
+export async function acceptEmployeeInviteOnChain(
+    provider: AnchorProvider,
+    payroll: PublicKey,
+    employeeId: string,
+    // Employee's ShadowWire confidential account (must belong to their wallet)
+    confidentialAccount: PublicKey,
+    screeningScore: number,
+    attestation: ScreeningAttestation
+): Promise<string> {
+    const program = new Program(idl as Idl, provider);
+    const employeeWallet = provider.wallet.publicKey;
+
+    const [invitePDA] = getInvitePDA(payroll, employeeId);
+    const [employeePDA] = getEmployeePDA(payroll, employeeId);
+    const [screeningHistoryPDA] = getScreeningHistoryPDA(employeePDA);
+    const [salaryHistoryPDA] = getSalaryHistoryPDA(employeePDA);
+    const [screeningProviderPDA] = getScreeningProviderPDA(payroll, attestation.provider);
+
+    // The invite's rent goes back to the HR admin who created it
+    const invite = await (program.account as any).employeeInvite.fetch(invitePDA);
+
+    console.log('Accepting employee invite on-chain:');
+    console.log('  ID:', employeeId);
+    console.log('  Wallet:', employeeWallet.toBase58());
+    console.log('  Confidential account:', confidentialAccount.toBase58());
+    console.log('  Score:', screeningScore);
+
+    const tx = await (program.methods as any)
+        .acceptEmployeeInvite(screeningScore, new BN(attestation.screenedAt))
+        .accounts({
+            payroll: payroll,
+            invite: invitePDA,
             employee: employeePDA,
+            screeningHistory: screeningHistoryPDA,
+            salaryHistory: salaryHistoryPDA,
             employeeWallet: employeeWallet,
-            confidentialAccount: employeeWallet, // Placeholder
-            authority: authority,
+            confidentialAccount: confidentialAccount,
+            screeningProvider: screeningProviderPDA,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            rentReceiver: invite.createdBy,
             systemProgram: SystemProgram.programId,
         })
+        // The attestation signature must immediately precede accept_employee_invite
+        .preInstructions([attestation.ed25519Instruction])
         .rpc();
 
-    console.log('Employee added! TX:', tx);
+    console.log('Invite accepted! TX:', tx);
     return tx;
 }
 
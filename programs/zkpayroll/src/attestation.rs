@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::errors::PayrollError;
+
+/// Domain separator prefixed to every signed screening attestation
+pub const ATTESTATION_DOMAIN: &[u8] = b"zkpayroll:screening:v1";
+
+/// Size of the Ed25519 program's per-signature offsets header
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Ed25519 program data starts with a signature count and a padding byte
+const ED25519_OFFSETS_START: usize = 2;
+/// Instruction index meaning "this Ed25519 instruction's own data"
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+const ED25519_PUBKEY_LEN: usize = 32;
+
+/// Range screening result signed by a registered screening provider
+///
+/// Signed message layout:
+/// `ATTESTATION_DOMAIN || wallet (32) || score (1) || screened_at (8, LE) || provider (32)`
+pub struct ScreeningAttestation {
+    /// Wallet that was screened
+    pub wallet: Pubkey,
+    /// Risk screening score (0-100)
+    pub score: u8,
+    /// Unix timestamp at which the provider screened the wallet
+    pub screened_at: i64,
+    /// Screening provider signing key
+    pub provider: Pubkey,
+}
+
+impl ScreeningAttestation {
+    /// Bytes the screening provider signs
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(ATTESTATION_DOMAIN.len() + 32 + 1 + 8 + 32);
+        message.extend_from_slice(ATTESTATION_DOMAIN);
+        message.extend_from_slice(self.wallet.as_ref());
+        message.push(self.score);
+        message.extend_from_slice(&self.screened_at.to_le_bytes());
+        message.extend_from_slice(self.provider.as_ref());
+        message
+    }
+
+    /// Verify the attestation was signed by `provider` and is younger
+    /// than `validity_seconds`
+    ///
+    /// The instruction immediately before the current one must be an
+    /// Ed25519 program instruction carrying exactly this signature; the
+    /// runtime has already checked the signature itself by the time we run.
+    pub fn verify(
+        &self,
+        instructions_sysvar: &AccountInfo,
+        now: i64,
+        validity_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            self.screened_at <= now,
+            PayrollError::InvalidAttestation
+        );
+        require!(
+            now - self.screened_at < validity_seconds,
+            PayrollError::ScreeningExpired
+        );
+
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        require!(current_index > 0, PayrollError::InvalidAttestation);
+        let ed25519_ix =
+            load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+        require_keys_eq!(
+            ed25519_ix.program_id,
+            ed25519_program::ID,
+            PayrollError::InvalidAttestation
+        );
+
+        let (signer, message) = parse_ed25519_instruction(&ed25519_ix.data)?;
+        require!(
+            signer == self.provider.as_ref(),
+            PayrollError::InvalidAttestation
+        );
+        require!(
+            message == self.message().as_slice(),
+            PayrollError::InvalidAttestation
+        );
+
+        Ok(())
+    }
+}
+
+/// Extract the public key and message from a single-signature Ed25519
+/// program instruction whose data is entirely self-contained
+fn parse_ed25519_instruction(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_LEN && data[0] == 1,
+        PayrollError::InvalidAttestation
+    );
+
+    let offsets = &data[ED25519_OFFSETS_START..ED25519_OFFSETS_START + ED25519_OFFSETS_LEN];
+    let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+
+    let signature_instruction_index = read_u16(2);
+    let public_key_offset = read_u16(4) as usize;
+    let public_key_instruction_index = read_u16(6);
+    let message_data_offset = read_u16(8) as usize;
+    let message_data_size = read_u16(10) as usize;
+    let message_instruction_index = read_u16(12);
+
+    // Signature, key and message must all live in the Ed25519 instruction
+    // itself, otherwise they could be pulled from attacker-chosen data
+    require!(
+        signature_instruction_index == ED25519_CURRENT_INSTRUCTION
+            && public_key_instruction_index == ED25519_CURRENT_INSTRUCTION
+            && message_instruction_index == ED25519_CURRENT_INSTRUCTION,
+        PayrollError::InvalidAttestation
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + ED25519_PUBKEY_LEN)
+        .ok_or(PayrollError::InvalidAttestation)?;
+    let message = data
+        .get(message_data_offset..message_data_offset + message_data_size)
+        .ok_or(PayrollError::InvalidAttestation)?;
+
+    Ok((public_key, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNATURE_LEN: usize = 64;
+
+    /// Single-signature Ed25519 program instruction data whose offsets
+    /// reference the given instruction indexes
+    fn ed25519_data(
+        public_key: &[u8; 32],
+        message: &[u8],
+        signature_ix: u16,
+        public_key_ix: u16,
+        message_ix: u16,
+    ) -> Vec<u8> {
+        let public_key_offset = ED25519_OFFSETS_START + ED25519_OFFSETS_LEN;
+        let signature_offset = public_key_offset + ED25519_PUBKEY_LEN;
+        let message_offset = signature_offset + SIGNATURE_LEN;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset as u16,
+            signature_ix,
+            public_key_offset as u16,
+            public_key_ix,
+            message_offset as u16,
+            message.len() as u16,
+            message_ix,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(public_key);
+        data.extend_from_slice(&[0u8; SIGNATURE_LEN]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn parses_self_contained_instruction() {
+        let current = ED25519_CURRENT_INSTRUCTION;
+        let data = ed25519_data(&[7u8; 32], b"attestation", current, current, current);
+
+        let (public_key, message) = parse_ed25519_instruction(&data).unwrap();
+        assert_eq!(public_key, &[7u8; 32]);
+        assert_eq!(message, b"attestation");
+    }
+
+    #[test]
+    fn rejects_offsets_into_other_instructions() {
+        let current = ED25519_CURRENT_INSTRUCTION;
+        for (signature_ix, public_key_ix, message_ix) in
+            [(0, current, current), (current, 0, current), (current, current, 0)]
+        {
+            let data =
+                ed25519_data(&[7u8; 32], b"attestation", signature_ix, public_key_ix, message_ix);
+            assert!(parse_ed25519_instruction(&data).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_message() {
+        let current = ED25519_CURRENT_INSTRUCTION;
+        let mut data = ed25519_data(&[7u8; 32], b"attestation", current, current, current);
+        data.truncate(data.len() - 1);
+        assert!(parse_ed25519_instruction(&data).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+// Error codes are assigned in declaration order: always append new variants
+// at the end so deployed clients keep their error mappings.
 #[error_code]
 pub enum PayrollError {
     /// Tax rate exceeds maximum (100% = 10000 bps)
@@ -7,10 +9,10 @@ pub enum PayrollError {
     InvalidTaxRate,
 
     /// Wallet screening score is below threshold
-    #[msg("Wallet screening failed: score below minimum threshold of 50")]
+    #[msg("Wallet screening failed: score below the payroll's minimum threshold")]
     ScreeningFailed,
 
-    /// Screening has expired (older than 24 hours)
+    /// Screening is older than the payroll's validity window
     #[msg("Wallet screening has expired, re-screening required")]
     ScreeningExpired,
 
@@ -18,12 +20,12 @@ pub enum PayrollError {
     #[msg("Zero-knowledge proof verification failed")]
     ProofVerificationFailed,
 
-    /// Invalid ShadowWire confidential account
+    /// Account is not a ShadowWire confidential account of the expected wallet
     #[msg("Invalid or malformed confidential account")]
     InvalidConfidentialAccount,
 
-    /// Insufficient balance in confidential account
-    #[msg("Insufficient balance in confidential account")]
+    /// Treasury vault balance does not cover the payment
+    #[msg("Insufficient balance in payroll treasury")]
     InsufficientBalance,
 
     /// Signer is not authorized
@@ -53,13 +55,238 @@ pub enum PayrollError {
     /// Tax amount exceeds salary
     #[msg("Tax amount cannot exceed salary amount")]
     TaxExceedsSalary,
+
+    /// Token account does not belong to the expected owner
+    #[msg("Token account is not owned by the expected wallet")]
+    InvalidTokenAccount,
+
+    /// Tax amount does not match the configured tax rate
+    #[msg("Tax amount does not match the payroll tax rate")]
+    TaxAmountMismatch,
+
+    /// Config change delay outside the allowed range
+    #[msg("Config delay must be between 0 and 30 days")]
+    InvalidConfigDelay,
+
+    /// No config change has been proposed
+    #[msg("No pending payroll config change")]
+    NoPendingConfig,
+
+    /// Config change timelock has not expired yet
+    #[msg("Pending payroll config change is still timelocked")]
+    ConfigTimelocked,
+
+    /// No authority transfer has been proposed
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+
+    /// Signer does not hold the role required by the instruction
+    #[msg("Unauthorized: signer does not hold the required payroll role")]
+    MissingRole,
+
+    /// Role registry has no room for another member
+    #[msg("Role registry is full")]
+    RoleRegistryFull,
+
+    /// Member does not hold the role being revoked
+    #[msg("Role has not been granted to this member")]
+    RoleNotGranted,
+
+    /// Approval policy is malformed
+    #[msg("Approval policy needs 1 to 10 unique approvers and a threshold between 1 and the approver count")]
+    InvalidApprovalPolicy,
+
+    /// Payroll requires M-of-N approval for payments
+    #[msg("Payroll requires approved payment proposals")]
+    ApprovalRequired,
+
+    /// Payroll has no approval policy configured
+    #[msg("Payroll has no approval policy")]
+    NoApprovalPolicy,
+
+    /// Signer is not a configured approver
+    #[msg("Signer is not a payment approver")]
+    NotApprover,
+
+    /// Approver has already approved this proposal
+    #[msg("Payment proposal already approved by this signer")]
+    AlreadyApproved,
+
+    /// Not enough approvals to execute the payment
+    #[msg("Payment proposal has not reached the approval threshold")]
+    InsufficientApprovals,
+
+    /// Employee is already active
+    #[msg("Employee is already active")]
+    EmployeeAlreadyActive,
+
+    /// Account is still inside its retention period
+    #[msg("Retention period has not elapsed")]
+    RetentionPeriodActive,
+
+    /// Payment has not been verified yet
+    #[msg("Payment has not been verified")]
+    PaymentNotVerified,
+
+    /// Screening attestation is missing, malformed or not signed by the provider
+    #[msg("Invalid screening attestation")]
+    InvalidAttestation,
+
+    /// Screening attestation is older than the one already recorded
+    #[msg("Screening attestation is older than the current screening")]
+    StaleAttestation,
+
+    /// Screening threshold or validity window out of range
+    #[msg("Screening threshold must be 0-100 and validity between 1 second and 30 days")]
+    InvalidScreeningPolicy,
+
+    /// Employee already received a regular payment for this period
+    #[msg("Employee has already been paid for this pay period")]
+    AlreadyPaidForPeriod,
+
+    /// Off-cycle payment index was already used
+    #[msg("Off-cycle payment index must exceed the employee's last off-cycle index")]
+    InvalidOffCycleIndex,
+
+    /// Batch entries don't line up with the remaining accounts
+    #[msg("Batch must have one entry per (employee, payment record, token account) triple")]
+    InvalidBatch,
+
+    /// Payment record PDA already exists or does not match its seeds
+    #[msg("Payment record account is invalid or already in use")]
+    InvalidPaymentRecord,
+
+    /// Payroll run is not in a state that allows this action
+    #[msg("Payroll run is not in the required state")]
+    InvalidRunStatus,
+
+    /// Payment period does not match the payroll run's period
+    #[msg("Payment period does not match the payroll run")]
+    RunPeriodMismatch,
+
+    /// Payroll run must expect at least one payment
+    #[msg("Payroll run must expect at least one payment")]
+    InvalidRunSize,
+
+    /// Payroll run would exceed its expected payment count
+    #[msg("Payroll run has already made all expected payments")]
+    RunCountExceeded,
+
+    /// Treasury vault has already been created
+    #[msg("Payroll treasury vault already initialized")]
+    TreasuryAlreadyInitialized,
+
+    /// Tax proof does not show tax = rate x salary for the recorded commitments
+    #[msg("Tax proof failed: committed tax does not match the tax rate")]
+    TaxProofFailed,
+
+    /// Payment does not match the employee's salary commitment
+    #[msg("Payment amounts do not match the employee's salary commitment")]
+    SalaryCommitmentMismatch,
+
+    /// Salary change must not take effect before the current pay period
+    #[msg("Salary change cannot take effect in a past pay period")]
+    InvalidEffectivePeriod,
+
+    /// Auditor key cannot be added or removed
+    #[msg("Auditor key is invalid, already registered, not registered, or the auditor limit is reached")]
+    InvalidAuditor,
+
+    /// Payment amounts must be disclosed to the payroll's auditors
+    #[msg("Payment audit is missing or does not cover every registered auditor")]
+    AuditRequired,
+
+    /// Minimum pay period length is out of range
+    #[msg("Minimum pay period length must be between 1 and 366 days")]
+    InvalidPeriodLength,
+
+    /// Current pay period is shorter than the minimum length
+    #[msg("Current pay period has not reached its minimum length")]
+    PeriodNotElapsed,
+
+    /// Payments for the current period must go through its open run
+    #[msg("Payments for the current period must count towards its open payroll run")]
+    RunRequired,
+
+    /// A payroll run is open and not yet finished
+    #[msg("A payroll run is still in progress")]
+    RunInProgress,
+
+    /// Withdrawal or off-cycle payment would take funds reserved for the
+    /// open payroll run
+    #[msg("Treasury funds are reserved for the open payroll run")]
+    FundsReserved,
+
+    /// Encrypted balance is not a valid authenticated-encryption ciphertext
+    #[msg("Invalid decryptable balance ciphertext")]
+    InvalidCiphertext,
+
+    /// Payment commitments do not commit to the amounts transferred
+    #[msg("Payment commitments do not match the amounts transferred")]
+    CommitmentMismatch,
+
+    /// Off-cycle payment without a second HR admin signature
+    #[msg("Off-cycle payments must be signed off by an HR admin other than the operator")]
+    OffCycleSignOffRequired,
+
+    /// Salary history has no commitment effective for the pay period
+    #[msg("No salary commitment is in force for the pay period")]
+    NoSalaryCommitment,
+
+    /// Payment proposal holds as many approvals as it has room for
+    #[msg("Payment proposal has no room for more approvals")]
+    ApprovalsFull,
+
+    /// Confidential proposals execute through execute_confidential_payment,
+    /// plaintext ones through execute_payment
+    #[msg("Payment proposal must be executed by the matching instruction")]
+    ProposalTypeMismatch,
 }
 
-/// Screening threshold - minimum score required
-pub const SCREENING_THRESHOLD: u8 = 50;
+/// Default screening threshold - minimum score required
+pub const DEFAULT_SCREENING_THRESHOLD: u8 = 50;
+
+/// Default screening validity period in seconds (24 hours)
+pub const DEFAULT_SCREENING_VALIDITY_SECONDS: i64 = 86400;
+
+/// Maximum screening score
+pub const MAX_SCREENING_SCORE: u8 = 100;
+
+/// Maximum screening validity period in seconds (30 days)
+pub const MAX_SCREENING_VALIDITY_SECONDS: i64 = 30 * 86400;
 
-/// Screening validity period in seconds (24 hours)
-pub const SCREENING_VALIDITY_SECONDS: i64 = 86400;
+/// Retention period after deactivation before an employee can be closed (30 days)
+pub const EMPLOYEE_RETENTION_SECONDS: i64 = 30 * 86400;
+
+/// Retention period after verification before a payment record can be closed (365 days)
+pub const PAYMENT_RECORD_RETENTION_SECONDS: i64 = 365 * 86400;
+
+/// Maximum notice period for payroll config changes (30 days)
+pub const MAX_CONFIG_DELAY_SECONDS: i64 = 30 * 86400;
+
+/// Default minimum pay period length in seconds (7 days)
+pub const DEFAULT_MIN_PERIOD_SECONDS: i64 = 7 * 86400;
+
+/// Shortest allowed minimum pay period length in seconds (1 day)
+pub const MIN_PERIOD_SECONDS_FLOOR: i64 = 86400;
+
+/// Longest allowed minimum pay period length in seconds (366 days)
+pub const MIN_PERIOD_SECONDS_CEILING: i64 = 366 * 86400;
 
 /// Maximum tax rate in basis points (100%)
 pub const MAX_TAX_RATE_BPS: u16 = 10000;
+
+/// Maximum number of employees paid by a single batch instruction
+///
+/// Sized to fit a 1232-byte v0 transaction whose non-signer accounts come
+/// from an address lookup table. Each entry costs 152 bytes: the 144-byte
+/// `BatchPayment` plus 2 bytes (lookup index and instruction account
+/// index) for each of its 4 accounts. The rest of the transaction takes
+/// 237 bytes: operator signature, header and blockhash, the operator and
+/// program keys, the instruction's 10 fixed accounts, the instruction data
+/// prefix and one lookup table. 237 + 6 * 152 = 1149 bytes, which leaves
+/// room for a compute budget instruction; a seventh entry would not fit.
+pub const MAX_BATCH_PAYMENTS: usize = 6;
+
+/// Length of a Token-2022 decryptable balance (authenticated-encryption ciphertext)
+pub const AE_CIPHERTEXT_LEN: usize = 36;
@@ -0,0 +1,443 @@
+use anchor_lang::prelude::*;
+use crate::state::{PaymentType, Role};
+
+/// Emitted when a payroll is initialized
+#[event]
+pub struct PayrollInitialized {
+    pub payroll: Pubkey,
+    pub payroll_id: u64,
+    pub authority: Pubkey,
+    pub tax_authority: Pubkey,
+    pub tax_confidential_account: Pubkey,
+    pub tax_rate_bps: u16,
+    pub shadowwire_program: Pubkey,
+    pub config_delay_seconds: i64,
+    pub screening_threshold: u8,
+    pub screening_validity_seconds: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the payroll moves to a new pay period
+#[event]
+pub struct PayPeriodAdvanced {
+    pub payroll: Pubkey,
+    pub previous_period: u64,
+    pub current_period: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payroll run is opened
+#[event]
+pub struct PayrollRunOpened {
+    pub payroll: Pubkey,
+    pub run: Pubkey,
+    pub period: u64,
+    pub expected_count: u32,
+    pub opened_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payroll run's funding is confirmed
+#[event]
+pub struct PayrollRunFunded {
+    pub payroll: Pubkey,
+    pub run: Pubkey,
+    pub funded_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payroll run makes its last expected payment
+#[event]
+pub struct PayrollRunCompleted {
+    pub payroll: Pubkey,
+    pub run: Pubkey,
+    pub processed_count: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payroll run is cancelled
+#[event]
+pub struct PayrollRunCancelled {
+    pub payroll: Pubkey,
+    pub run: Pubkey,
+    pub processed_count: u32,
+    pub expected_count: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the payroll treasury vault is created
+#[event]
+pub struct TreasuryInitialized {
+    pub payroll: Pubkey,
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when tokens are deposited into the treasury vault
+#[event]
+pub struct TreasuryDeposited {
+    pub payroll: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority withdraws from the treasury vault
+#[event]
+pub struct TreasuryWithdrawn {
+    pub payroll: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the confidential treasury vault is created
+#[event]
+pub struct ConfidentialTreasuryInitialized {
+    pub payroll: Pubkey,
+    pub treasury: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the confidential treasury's public balance is deposited
+/// into its confidential balance
+#[event]
+pub struct ConfidentialTreasuryDeposited {
+    pub payroll: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority withdraws from the confidential treasury
+#[event]
+pub struct ConfidentialTreasuryWithdrawn {
+    pub payroll: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payroll config change is proposed
+#[event]
+pub struct PayrollConfigProposed {
+    pub payroll: Pubkey,
+    pub tax_rate_bps: u16,
+    pub tax_authority: Pubkey,
+    pub tax_confidential_account: Pubkey,
+    pub shadowwire_program: Pubkey,
+    pub config_delay_seconds: i64,
+    pub min_period_seconds: i64,
+    pub effective_at: i64,
+}
+
+/// Emitted when a pending payroll config change is applied
+#[event]
+pub struct PayrollConfigApplied {
+    pub payroll: Pubkey,
+    pub tax_rate_bps: u16,
+    pub tax_authority: Pubkey,
+    pub tax_confidential_account: Pubkey,
+    pub shadowwire_program: Pubkey,
+    pub config_delay_seconds: i64,
+    pub min_period_seconds: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a pending payroll config change is cancelled
+#[event]
+pub struct PayrollConfigCancelled {
+    pub payroll: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a new payroll authority is proposed
+#[event]
+pub struct AuthorityProposed {
+    pub payroll: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a proposed authority accepts control of the payroll
+#[event]
+pub struct AuthorityTransferred {
+    pub payroll: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payroll role is granted
+#[event]
+pub struct RoleGranted {
+    pub payroll: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payroll role is revoked
+#[event]
+pub struct RoleRevoked {
+    pub payroll: Pubkey,
+    pub member: Pubkey,
+    pub role: Role,
+    pub timestamp: i64,
+}
+
+/// Emitted when the payment approval policy is set or removed
+#[event]
+pub struct ApprovalPolicySet {
+    pub payroll: Pubkey,
+    /// Empty when the policy is removed
+    pub approvers: Vec<Pubkey>,
+    /// Zero when the policy is removed
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when the payroll's screening policy changes
+#[event]
+pub struct ScreeningPolicyUpdated {
+    pub payroll: Pubkey,
+    pub screening_threshold: u8,
+    pub screening_validity_seconds: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an auditor key is registered
+#[event]
+pub struct AuditorAdded {
+    pub payroll: Pubkey,
+    pub auditor: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when an auditor key is removed
+#[event]
+pub struct AuditorRemoved {
+    pub payroll: Pubkey,
+    pub auditor: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a screening provider key is registered
+#[event]
+pub struct ScreeningProviderRegistered {
+    pub payroll: Pubkey,
+    pub provider: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a screening provider key is removed
+#[event]
+pub struct ScreeningProviderRemoved {
+    pub payroll: Pubkey,
+    pub provider: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an employee is added
+#[event]
+pub struct EmployeeAdded {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub employee_id: String,
+    pub wallet: Pubkey,
+    pub confidential_account: Pubkey,
+    pub screening_score: u8,
+    pub screening_provider: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an employee is invited to join a payroll
+#[event]
+pub struct EmployeeInvited {
+    pub payroll: Pubkey,
+    pub invite: Pubkey,
+    pub employee_id: String,
+    pub wallet: Pubkey,
+    pub created_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when HR cancels an employee invite before it is accepted
+#[event]
+pub struct EmployeeInviteCancelled {
+    pub payroll: Pubkey,
+    pub invite: Pubkey,
+    pub employee_id: String,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an employee's salary commitment is changed
+#[event]
+pub struct SalaryCommitmentUpdated {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub salary_commitment: [u8; 32],
+    pub effective_period: u64,
+    pub changed_by: Pubkey,
+    pub employee_signed: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when an employee's wallet and confidential account are rotated
+#[event]
+pub struct EmployeeWalletUpdated {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub previous_wallet: Pubkey,
+    pub wallet: Pubkey,
+    pub confidential_account: Pubkey,
+    /// True if the payroll authority approved the change instead of the
+    /// previous wallet
+    pub recovery: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when an employee is deactivated
+#[event]
+pub struct EmployeeDeactivated {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub employee_id: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when a deactivated employee is reactivated
+#[event]
+pub struct EmployeeReactivated {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub employee_id: String,
+    pub screening_score: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a deactivated employee account is closed
+#[event]
+pub struct EmployeeClosed {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub employee_id: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when an employee's screening score is updated
+#[event]
+pub struct ScreeningUpdated {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub screening_score: u8,
+    pub screening_provider: Pubkey,
+    /// Unix timestamp of the screening (as attested by the provider)
+    pub screened_at: i64,
+    /// False if the new score deactivated the employee
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payment is proposed for approval
+#[event]
+pub struct PaymentProposed {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub period: u64,
+    pub payment_type: PaymentType,
+}
+
+/// Emitted when an approver signs off on a payment proposal
+#[event]
+pub struct PaymentApproved {
+    pub payroll: Pubkey,
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payment proposal is cancelled without being executed
+#[event]
+pub struct PaymentCancelled {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub proposal: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payment is made and its record created (NO amounts!)
+#[event]
+pub struct PaymentProcessed {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub payment_record: Pubkey,
+    pub tax_confidential_account: Pubkey,
+    pub employee_confidential_account: Pubkey,
+    pub period: u64,
+    pub payment_type: PaymentType,
+    /// Payroll run the payment belongs to (default if paid outside a run)
+    pub run: Pubkey,
+    /// True for Token-2022 confidential transfers
+    pub confidential: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a batch entry is skipped instead of paid
+#[event]
+pub struct PaymentSkipped {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub period: u64,
+    /// Error code explaining why the entry was skipped (the program
+    /// error's `u64` value: custom codes in the low 32 bits, builtin
+    /// errors in the high 32 bits)
+    pub error_code: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted once a batch payroll run has been processed
+#[event]
+pub struct PayrollBatchProcessed {
+    pub payroll: Pubkey,
+    pub period: u64,
+    pub paid: u32,
+    pub skipped: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payment's amounts are disclosed to the auditors
+#[event]
+pub struct PaymentAuditRecorded {
+    pub payroll: Pubkey,
+    pub payment_record: Pubkey,
+    pub payment_audit: Pubkey,
+    pub auditor_count: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a verified payment record is closed
+#[event]
+pub struct PaymentRecordClosed {
+    pub payroll: Pubkey,
+    pub employee: Pubkey,
+    pub payment_record: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a payment proof is verified
+#[event]
+pub struct ProofVerified {
+    pub payment_record: Pubkey,
+    pub range_proof_context: Pubkey,
+    pub tax_proof_context: Pubkey,
+    pub verifier: Pubkey,
+    pub verified_at: i64,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::AuthorityTransferred;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.pending_authority.is_some() @ PayrollError::NoPendingAuthority,
+        constraint = payroll.pending_authority == Some(new_authority.key())
+            @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Proposed authority (must match payroll.pending_authority)
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let payroll = &mut ctx.accounts.payroll;
+    let previous_authority = payroll.authority;
+
+    payroll.authority = ctx.accounts.new_authority.key();
+    payroll.pending_authority = None;
+
+    emit!(AuthorityTransferred {
+        payroll: payroll.key(),
+        previous_authority,
+        new_authority: payroll.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Authority transferred");
+    msg!("Previous authority: {}", previous_authority);
+    msg!("New authority: {}", payroll.authority);
+
+    Ok(())
+}
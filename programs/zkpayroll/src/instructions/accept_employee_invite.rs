@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::attestation::ScreeningAttestation;
+use crate::state::{
+    Payroll, Employee, EmployeeInvite, SalaryEntry, SalaryHistory, ScreeningEntry,
+    ScreeningHistory, ScreeningProvider,
+};
+use crate::errors::PayrollError;
+use crate::events::EmployeeAdded;
+use crate::shadowwire;
+
+#[derive(Accounts)]
+pub struct AcceptEmployeeInvite<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Invite being accepted (closed on acceptance)
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"invite", payroll.key().as_ref(), invite.employee_id.as_bytes()],
+        bump = invite.bump
+    )]
+    pub invite: Account<'info, EmployeeInvite>,
+
+    /// Employee account to create (PDA)
+    #[account(
+        init,
+        payer = employee_wallet,
+        space = Employee::LEN,
+        seeds = [b"employee", payroll.key().as_ref(), invite.employee_id.as_bytes()],
+        bump
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// Employee's screening history (PDA)
+    #[account(
+        init,
+        payer = employee_wallet,
+        space = ScreeningHistory::LEN,
+        seeds = [b"screening_history", employee.key().as_ref()],
+        bump
+    )]
+    pub screening_history: Account<'info, ScreeningHistory>,
+
+    /// Employee's salary commitment history (PDA)
+    #[account(
+        init,
+        payer = employee_wallet,
+        space = SalaryHistory::LEN,
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
+    /// Employee's wallet (must be the invited wallet; proves control by signing)
+    #[account(
+        mut,
+        constraint = employee_wallet.key() == invite.wallet @ PayrollError::Unauthorized
+    )]
+    pub employee_wallet: Signer<'info>,
+
+    /// Employee's ShadowWire confidential account
+    /// CHECK: Must be a ShadowWire confidential account of the employee wallet
+    #[account(
+        constraint = shadowwire::is_confidential_account_of(
+            &confidential_account,
+            &payroll.shadowwire_program,
+            &employee_wallet.key()
+        ) @ PayrollError::InvalidConfidentialAccount
+    )]
+    pub confidential_account: UncheckedAccount<'info>,
+
+    /// Screening provider that signed the attestation
+    #[account(
+        seeds = [
+            b"screening_provider",
+            payroll.key().as_ref(),
+            screening_provider.provider.as_ref()
+        ],
+        bump = screening_provider.bump
+    )]
+    pub screening_provider: Account<'info, ScreeningProvider>,
+
+    /// Instructions sysvar (holds the Ed25519 attestation signature)
+    /// CHECK: Address is checked against the sysvar ID
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// HR admin who created the invite (receives the invite's rent)
+    /// CHECK: Must match invite.created_by
+    #[account(
+        mut,
+        constraint = rent_receiver.key() == invite.created_by @ PayrollError::Unauthorized
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AcceptEmployeeInvite>,
+    screening_score: u8,
+    screened_at: i64,
+) -> Result<()> {
+    // Validate screening score meets threshold
+    require!(
+        screening_score >= ctx.accounts.payroll.screening_threshold,
+        PayrollError::ScreeningFailed
+    );
+
+    let clock = Clock::get()?;
+
+    // Score must be attested by a registered screening provider
+    let attestation = ScreeningAttestation {
+        wallet: ctx.accounts.employee_wallet.key(),
+        score: screening_score,
+        screened_at,
+        provider: ctx.accounts.screening_provider.provider,
+    };
+    attestation.verify(
+        &ctx.accounts.instructions_sysvar,
+        clock.unix_timestamp,
+        ctx.accounts.payroll.screening_validity_seconds,
+    )?;
+
+    // Reimburse the employee for the rent of the accounts created above
+    let prefunded_lamports = ctx.accounts.invite.prefunded_lamports;
+    ctx.accounts.invite.sub_lamports(prefunded_lamports)?;
+    ctx.accounts.employee_wallet.add_lamports(prefunded_lamports)?;
+
+    let invite = &ctx.accounts.invite;
+    let employee = &mut ctx.accounts.employee;
+    let payroll = &mut ctx.accounts.payroll;
+
+    // Initialize employee account from the invite
+    employee.payroll = payroll.key();
+    employee.employee_id = invite.employee_id.clone();
+    employee.name = invite.name.clone();
+    employee.wallet = ctx.accounts.employee_wallet.key();
+    employee.salary_commitment = invite.salary_commitment;
+    employee.screening_score = screening_score;
+    employee.last_screened = screened_at;
+    employee.screening_provider = attestation.provider;
+    employee.is_active = true;
+    employee.deactivated_at = 0;
+    employee.confidential_account = ctx.accounts.confidential_account.key();
+    employee.bump = ctx.bumps.employee;
+    employee.last_paid_period = 0;
+    employee.last_off_cycle_index = 0;
+
+    // Start the screening history with the onboarding result
+    let screening_history = &mut ctx.accounts.screening_history;
+    screening_history.employee = employee.key();
+    screening_history.bump = ctx.bumps.screening_history;
+    screening_history.record(ScreeningEntry {
+        score: screening_score,
+        screened_at,
+        provider: attestation.provider,
+        submitted_by: employee.wallet,
+        recorded_at: clock.unix_timestamp,
+    });
+
+    // Start the salary history with the invited commitment
+    let salary_history = &mut ctx.accounts.salary_history;
+    salary_history.employee = employee.key();
+    salary_history.bump = ctx.bumps.salary_history;
+    salary_history.record(SalaryEntry {
+        commitment: invite.salary_commitment,
+        effective_period: 0,
+        changed_by: invite.created_by,
+        employee_signed: true,
+        recorded_at: clock.unix_timestamp,
+    });
+
+    // Update payroll stats
+    payroll.employee_count = payroll.employee_count.checked_add(1).unwrap();
+
+    emit!(EmployeeAdded {
+        payroll: payroll.key(),
+        employee: employee.key(),
+        employee_id: employee.employee_id.clone(),
+        wallet: employee.wallet,
+        confidential_account: employee.confidential_account,
+        screening_score,
+        screening_provider: attestation.provider,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Employee invite accepted: {} ({})", employee.name, employee.employee_id);
+    msg!("Screening score: {}/100", screening_score);
+    msg!("Confidential account: {}", employee.confidential_account);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::AuditorAdded;
+
+#[derive(Accounts)]
+pub struct AddAuditor<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AddAuditor>, auditor: [u8; 32]) -> Result<()> {
+    let payroll = &mut ctx.accounts.payroll;
+
+    require!(auditor != [0u8; 32], PayrollError::InvalidAuditor);
+    require!(
+        payroll.auditors.len() < Payroll::MAX_AUDITORS,
+        PayrollError::InvalidAuditor
+    );
+    require!(
+        !payroll.auditors.contains(&auditor),
+        PayrollError::InvalidAuditor
+    );
+
+    payroll.auditors.push(auditor);
+
+    emit!(AuditorAdded {
+        payroll: payroll.key(),
+        auditor,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Auditor added ({} of {})", payroll.auditors.len(), Payroll::MAX_AUDITORS);
+
+    Ok(())
+}
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
-use crate::state::{Payroll, Employee};
-use crate::errors::{PayrollError, SCREENING_THRESHOLD};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::attestation::ScreeningAttestation;
+use crate::state::{
+    Payroll, Employee, SalaryEntry, SalaryHistory, ScreeningEntry, ScreeningHistory,
+    ScreeningProvider,
+};
+use crate::errors::PayrollError;
+use crate::events::EmployeeAdded;
+use crate::shadowwire;
 
 #[derive(Accounts)]
 #[instruction(employee_id: String)]
@@ -8,7 +15,11 @@ pub struct AddEmployee<'info> {
     /// Payroll account (must exist)
     #[account(
         mut,
-        seeds = [b"payroll", authority.key().as_ref()],
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
         bump = payroll.bump,
         has_one = authority @ PayrollError::Unauthorized
     )]
@@ -24,15 +35,59 @@ pub struct AddEmployee<'info> {
     )]
     pub employee: Account<'info, Employee>,
 
-    /// Employee's wallet address
-    /// CHECK: Just stored as reference
-    pub employee_wallet: UncheckedAccount<'info>,
+    /// Employee's screening history (PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = ScreeningHistory::LEN,
+        seeds = [b"screening_history", employee.key().as_ref()],
+        bump
+    )]
+    pub screening_history: Account<'info, ScreeningHistory>,
+
+    /// Employee's salary commitment history (PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = SalaryHistory::LEN,
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
+    /// Employee's wallet (signs to confirm they control it, like
+    /// accept_employee_invite)
+    pub employee_wallet: Signer<'info>,
 
     /// Employee's ShadowWire confidential account
-    /// CHECK: Should be a valid ShadowWire account, validated off-chain
+    /// CHECK: Must be a ShadowWire confidential account of the employee wallet
+    #[account(
+        constraint = shadowwire::is_confidential_account_of(
+            &confidential_account,
+            &payroll.shadowwire_program,
+            &employee_wallet.key()
+        ) @ PayrollError::InvalidConfidentialAccount
+    )]
     pub confidential_account: UncheckedAccount<'info>,
 
+    /// Screening provider that signed the attestation
+    #[account(
+        seeds = [
+            b"screening_provider",
+            payroll.key().as_ref(),
+            screening_provider.provider.as_ref()
+        ],
+        bump = screening_provider.bump
+    )]
+    pub screening_provider: Account<'info, ScreeningProvider>,
+
+    /// Instructions sysvar (holds the Ed25519 attestation signature)
+    /// CHECK: Address is checked against the sysvar ID
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
     /// Authority (must match payroll.authority)
+    /// HR admins onboard employees through invites instead
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -45,6 +100,7 @@ pub fn handler(
     name: String,
     salary_commitment: [u8; 32],
     screening_score: u8,
+    screened_at: i64,
 ) -> Result<()> {
     // Validate input lengths
     require!(
@@ -58,11 +114,25 @@ pub fn handler(
 
     // Validate screening score meets threshold
     require!(
-        screening_score >= SCREENING_THRESHOLD,
+        screening_score >= ctx.accounts.payroll.screening_threshold,
         PayrollError::ScreeningFailed
     );
 
     let clock = Clock::get()?;
+
+    // Score must be attested by a registered screening provider
+    let attestation = ScreeningAttestation {
+        wallet: ctx.accounts.employee_wallet.key(),
+        score: screening_score,
+        screened_at,
+        provider: ctx.accounts.screening_provider.provider,
+    };
+    attestation.verify(
+        &ctx.accounts.instructions_sysvar,
+        clock.unix_timestamp,
+        ctx.accounts.payroll.screening_validity_seconds,
+    )?;
+
     let employee = &mut ctx.accounts.employee;
     let payroll = &mut ctx.accounts.payroll;
 
@@ -73,14 +143,53 @@ pub fn handler(
     employee.wallet = ctx.accounts.employee_wallet.key();
     employee.salary_commitment = salary_commitment;
     employee.screening_score = screening_score;
-    employee.last_screened = clock.unix_timestamp;
+    employee.last_screened = screened_at;
+    employee.screening_provider = attestation.provider;
     employee.is_active = true;
+    employee.deactivated_at = 0;
     employee.confidential_account = ctx.accounts.confidential_account.key();
     employee.bump = ctx.bumps.employee;
+    employee.last_paid_period = 0;
+    employee.last_off_cycle_index = 0;
+
+    // Start the screening history with the onboarding result
+    let screening_history = &mut ctx.accounts.screening_history;
+    screening_history.employee = employee.key();
+    screening_history.bump = ctx.bumps.screening_history;
+    screening_history.record(ScreeningEntry {
+        score: screening_score,
+        screened_at,
+        provider: attestation.provider,
+        submitted_by: ctx.accounts.authority.key(),
+        recorded_at: clock.unix_timestamp,
+    });
+
+    // Start the salary history with the onboarding commitment
+    let salary_history = &mut ctx.accounts.salary_history;
+    salary_history.employee = employee.key();
+    salary_history.bump = ctx.bumps.salary_history;
+    salary_history.record(SalaryEntry {
+        commitment: salary_commitment,
+        effective_period: 0,
+        changed_by: ctx.accounts.authority.key(),
+        employee_signed: false,
+        recorded_at: clock.unix_timestamp,
+    });
 
     // Update payroll stats
     payroll.employee_count = payroll.employee_count.checked_add(1).unwrap();
 
+    emit!(EmployeeAdded {
+        payroll: payroll.key(),
+        employee: employee.key(),
+        employee_id: employee_id.clone(),
+        wallet: employee.wallet,
+        confidential_account: employee.confidential_account,
+        screening_score,
+        screening_provider: attestation.provider,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Employee added: {} ({})", name, employee_id);
     msg!("Screening score: {}/100", screening_score);
     msg!("Confidential account: {}", employee.confidential_account);
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, Role, RoleRegistry};
+use crate::errors::PayrollError;
+use crate::events::PayPeriodAdvanced;
+
+#[derive(Accounts)]
+pub struct AdvancePayPeriod<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.active_run.is_none() @ PayrollError::RunInProgress
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Payroll operator (payroll authority or role holder)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AdvancePayPeriod>) -> Result<()> {
+    let clock = Clock::get()?;
+    let payroll = &mut ctx.accounts.payroll;
+
+    // A period must run its minimum length, so an operator cannot cycle
+    // periods to make extra regular payments
+    let period_ends_at = payroll
+        .period_started_at
+        .checked_add(payroll.min_period_seconds)
+        .unwrap();
+    require!(
+        clock.unix_timestamp >= period_ends_at,
+        PayrollError::PeriodNotElapsed
+    );
+
+    let previous_period = payroll.current_period;
+    payroll.current_period = previous_period.checked_add(1).unwrap();
+    payroll.period_started_at = clock.unix_timestamp;
+
+    emit!(PayPeriodAdvanced {
+        payroll: payroll.key(),
+        previous_period,
+        current_period: payroll.current_period,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pay period {} closed", previous_period);
+    msg!("Pay period {} started", payroll.current_period);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::PayrollConfigApplied;
+
+#[derive(Accounts)]
+pub struct ApplyPayrollConfig<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApplyPayrollConfig>) -> Result<()> {
+    let clock = Clock::get()?;
+    let payroll = &mut ctx.accounts.payroll;
+
+    let pending = payroll
+        .pending_config
+        .take()
+        .ok_or(PayrollError::NoPendingConfig)?;
+
+    require!(
+        clock.unix_timestamp >= pending.effective_at,
+        PayrollError::ConfigTimelocked
+    );
+
+    payroll.tax_rate_bps = pending.tax_rate_bps;
+    payroll.tax_authority = pending.tax_authority;
+    payroll.tax_confidential_account = pending.tax_confidential_account;
+    payroll.shadowwire_program = pending.shadowwire_program;
+    payroll.config_delay_seconds = pending.config_delay_seconds;
+    payroll.min_period_seconds = pending.min_period_seconds;
+
+    emit!(PayrollConfigApplied {
+        payroll: payroll.key(),
+        tax_rate_bps: payroll.tax_rate_bps,
+        tax_authority: payroll.tax_authority,
+        tax_confidential_account: payroll.tax_confidential_account,
+        shadowwire_program: payroll.shadowwire_program,
+        config_delay_seconds: payroll.config_delay_seconds,
+        min_period_seconds: payroll.min_period_seconds,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payroll config applied");
+    msg!("Tax rate: {}%", payroll.tax_rate_bps as f64 / 100.0);
+    msg!("Tax authority: {}", payroll.tax_authority);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, PaymentProposal};
+use crate::errors::PayrollError;
+use crate::events::PaymentApproved;
+
+#[derive(Accounts)]
+pub struct ApprovePayment<'info> {
+    /// Payroll configuration
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.approval_policy.as_ref()
+            .is_some_and(|policy| policy.is_approver(&approver.key()))
+            @ PayrollError::NotApprover
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Payment proposal to approve
+    #[account(
+        mut,
+        has_one = payroll @ PayrollError::Unauthorized
+    )]
+    pub proposal: Account<'info, PaymentProposal>,
+
+    /// Approver (must be in payroll.approval_policy)
+    pub approver: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApprovePayment>) -> Result<()> {
+    let policy = ctx.accounts.payroll.approval_policy.as_ref().unwrap();
+    let proposal = &mut ctx.accounts.proposal;
+
+    let approvals = proposal.approve(policy, ctx.accounts.approver.key())?;
+
+    emit!(PaymentApproved {
+        payroll: proposal.payroll,
+        proposal: proposal.key(),
+        approver: ctx.accounts.approver.key(),
+        approvals: approvals as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Payment proposal approved: {}", proposal.key());
+    msg!("Approved by: {}", ctx.accounts.approver.key());
+    msg!("Approvals: {}/{}", approvals, policy.threshold);
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, EmployeeInvite, Role, RoleRegistry};
+use crate::errors::PayrollError;
+use crate::events::EmployeeInviteCancelled;
+
+#[derive(Accounts)]
+pub struct CancelEmployeeInvite<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the HR admin role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Invite to cancel (closed, returning its rent and prefunded lamports)
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"invite", payroll.key().as_ref(), invite.employee_id.as_bytes()],
+        bump = invite.bump
+    )]
+    pub invite: Account<'info, EmployeeInvite>,
+
+    /// HR admin who created the invite (receives its lamports)
+    /// CHECK: Must match invite.created_by
+    #[account(
+        mut,
+        constraint = rent_receiver.key() == invite.created_by @ PayrollError::Unauthorized
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// HR admin (payroll authority or role holder)
+    pub hr_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelEmployeeInvite>) -> Result<()> {
+    let invite = &ctx.accounts.invite;
+
+    emit!(EmployeeInviteCancelled {
+        payroll: invite.payroll,
+        invite: invite.key(),
+        employee_id: invite.employee_id.clone(),
+        cancelled_by: ctx.accounts.hr_admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Employee invite cancelled: {}", invite.employee_id);
+    msg!("Cancelled by: {}", ctx.accounts.hr_admin.key());
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, PaymentProposal};
+use crate::errors::PayrollError;
+use crate::events::PaymentCancelled;
+
+#[derive(Accounts)]
+pub struct CancelPayment<'info> {
+    /// Payroll configuration
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Payment proposal to cancel (closed, freeing its period slot)
+    #[account(
+        mut,
+        close = proposer,
+        has_one = payroll @ PayrollError::Unauthorized,
+        has_one = proposer @ PayrollError::Unauthorized
+    )]
+    pub proposal: Account<'info, PaymentProposal>,
+
+    /// Operator who created the proposal (receives its rent)
+    /// CHECK: Must match proposal.proposer
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Proposer or payroll authority
+    #[account(
+        constraint = canceller.key() == proposal.proposer
+            || canceller.key() == payroll.authority @ PayrollError::Unauthorized
+    )]
+    pub canceller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelPayment>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+
+    emit!(PaymentCancelled {
+        payroll: proposal.payroll,
+        employee: proposal.employee,
+        proposal: proposal.key(),
+        cancelled_by: ctx.accounts.canceller.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Payment proposal cancelled: {}", proposal.key());
+    msg!("Cancelled by: {}", ctx.accounts.canceller.key());
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::PayrollConfigCancelled;
+
+#[derive(Accounts)]
+pub struct CancelPayrollConfig<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized,
+        constraint = payroll.pending_config.is_some() @ PayrollError::NoPendingConfig
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelPayrollConfig>) -> Result<()> {
+    let payroll = &mut ctx.accounts.payroll;
+
+    payroll.pending_config = None;
+
+    emit!(PayrollConfigCancelled {
+        payroll: payroll.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Pending payroll config change cancelled");
+
+    Ok(())
+}
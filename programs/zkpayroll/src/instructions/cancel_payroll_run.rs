@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, PayrollRun, Role, RoleRegistry};
+use crate::errors::PayrollError;
+use crate::events::PayrollRunCancelled;
+
+#[derive(Accounts)]
+pub struct CancelPayrollRun<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Active payroll run to cancel; kept as a record of the payments it made
+    #[account(
+        mut,
+        has_one = payroll @ PayrollError::Unauthorized,
+        constraint = payroll.active_run == Some(payroll_run.key()) @ PayrollError::InvalidRunStatus
+    )]
+    pub payroll_run: Account<'info, PayrollRun>,
+
+    /// Payroll operator (payroll authority or role holder)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelPayrollRun>) -> Result<()> {
+    let clock = Clock::get()?;
+    let payroll_run = &mut ctx.accounts.payroll_run;
+    payroll_run.cancel(clock.unix_timestamp)?;
+
+    // Payments for the period no longer need a run and the reservation lapses
+    ctx.accounts.payroll.release_run();
+
+    emit!(PayrollRunCancelled {
+        payroll: payroll_run.payroll,
+        run: payroll_run.key(),
+        processed_count: payroll_run.processed_count,
+        expected_count: payroll_run.expected_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payroll run for period {} cancelled", payroll_run.period);
+    msg!("Payments made: {}/{}", payroll_run.processed_count, payroll_run.expected_count);
+
+    Ok(())
+}
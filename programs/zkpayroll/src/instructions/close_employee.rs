@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, Employee, Role, RoleRegistry, SalaryHistory, ScreeningHistory};
+use crate::errors::{PayrollError, EMPLOYEE_RETENTION_SECONDS};
+use crate::events::EmployeeClosed;
+
+#[derive(Accounts)]
+pub struct CloseEmployee<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the HR admin role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Deactivated employee to close
+    #[account(
+        mut,
+        close = rent_receiver,
+        constraint = employee.payroll == payroll.key() @ PayrollError::Unauthorized,
+        constraint = !employee.is_active @ PayrollError::EmployeeAlreadyActive
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// Employee's screening history (closed with the employee)
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"screening_history", employee.key().as_ref()],
+        bump = screening_history.bump
+    )]
+    pub screening_history: Account<'info, ScreeningHistory>,
+
+    /// Employee's salary history (closed with the employee)
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump = salary_history.bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
+    /// Employer wallet that receives the reclaimed rent
+    /// CHECK: Must match payroll.authority
+    #[account(
+        mut,
+        constraint = rent_receiver.key() == payroll.authority @ PayrollError::Unauthorized
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// HR admin (payroll authority or role holder)
+    pub hr_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseEmployee>) -> Result<()> {
+    let clock = Clock::get()?;
+    let employee = &ctx.accounts.employee;
+
+    // Keep deactivated employees around for the retention period
+    let retained_until = employee
+        .deactivated_at
+        .checked_add(EMPLOYEE_RETENTION_SECONDS)
+        .unwrap();
+    require!(
+        clock.unix_timestamp >= retained_until,
+        PayrollError::RetentionPeriodActive
+    );
+
+    emit!(EmployeeClosed {
+        payroll: employee.payroll,
+        employee: employee.key(),
+        employee_id: employee.employee_id.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Employee closed: {} ({})", employee.name, employee.employee_id);
+    msg!("Rent returned to: {}", ctx.accounts.rent_receiver.key());
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, PaymentAudit, PaymentRecord, Role, RoleRegistry};
+use crate::errors::{PayrollError, PAYMENT_RECORD_RETENTION_SECONDS};
+use crate::events::PaymentRecordClosed;
+
+#[derive(Accounts)]
+pub struct ClosePaymentRecord<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Verified payment record to close
+    #[account(
+        mut,
+        close = rent_receiver,
+        has_one = payroll @ PayrollError::Unauthorized,
+        constraint = payment_record.verified @ PayrollError::PaymentNotVerified
+    )]
+    pub payment_record: Account<'info, PaymentRecord>,
+
+    /// Auditor disclosure for the record (required when the record has
+    /// auditors, closed with it)
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"audit", payment_record.key().as_ref()],
+        bump = payment_audit.bump
+    )]
+    pub payment_audit: Option<Account<'info, PaymentAudit>>,
+
+    /// Employer wallet that receives the reclaimed rent
+    /// CHECK: Must match payroll.authority
+    #[account(
+        mut,
+        constraint = rent_receiver.key() == payroll.authority @ PayrollError::Unauthorized
+    )]
+    pub rent_receiver: UncheckedAccount<'info>,
+
+    /// Payroll operator (payroll authority or role holder)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClosePaymentRecord>) -> Result<()> {
+    let clock = Clock::get()?;
+    let payment_record = &ctx.accounts.payment_record;
+
+    // The disclosure goes with the record, never stays behind on its own
+    require!(
+        payment_record.auditors.is_empty() || ctx.accounts.payment_audit.is_some(),
+        PayrollError::AuditRequired
+    );
+
+    // Keep verified records around for the retention period
+    let retained_until = payment_record
+        .verified_at
+        .checked_add(PAYMENT_RECORD_RETENTION_SECONDS)
+        .unwrap();
+    require!(
+        clock.unix_timestamp >= retained_until,
+        PayrollError::RetentionPeriodActive
+    );
+
+    emit!(PaymentRecordClosed {
+        payroll: payment_record.payroll,
+        employee: payment_record.employee,
+        payment_record: payment_record.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payment record closed: {}", payment_record.key());
+    msg!("Rent returned to: {}", ctx.accounts.rent_receiver.key());
+
+    Ok(())
+}
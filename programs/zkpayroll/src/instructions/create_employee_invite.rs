@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::{
+    Payroll, Employee, EmployeeInvite, Role, RoleRegistry, SalaryHistory, ScreeningHistory,
+};
+use crate::errors::PayrollError;
+use crate::events::EmployeeInvited;
+
+#[derive(Accounts)]
+#[instruction(employee_id: String)]
+pub struct CreateEmployeeInvite<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the HR admin role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Employee invite to create (PDA)
+    #[account(
+        init,
+        payer = hr_admin,
+        space = EmployeeInvite::LEN,
+        seeds = [b"invite", payroll.key().as_ref(), employee_id.as_bytes()],
+        bump
+    )]
+    pub invite: Account<'info, EmployeeInvite>,
+
+    /// HR admin (payroll authority or role holder)
+    #[account(mut)]
+    pub hr_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateEmployeeInvite>,
+    employee_id: String,
+    name: String,
+    wallet: Pubkey,
+    salary_commitment: [u8; 32],
+) -> Result<()> {
+    // Validate input lengths
+    require!(
+        employee_id.len() <= Employee::MAX_ID_LEN,
+        PayrollError::EmployeeIdTooLong
+    );
+    require!(
+        name.len() <= Employee::MAX_NAME_LEN,
+        PayrollError::EmployeeNameTooLong
+    );
+
+    let clock = Clock::get()?;
+
+    // Prefund the rent of the accounts the employee creates on acceptance,
+    // so onboarding costs the employee nothing
+    let rent = Rent::get()?;
+    let prefunded_lamports = rent.minimum_balance(Employee::LEN)
+        + rent.minimum_balance(ScreeningHistory::LEN)
+        + rent.minimum_balance(SalaryHistory::LEN);
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.hr_admin.to_account_info(),
+                to: ctx.accounts.invite.to_account_info(),
+            },
+        ),
+        prefunded_lamports,
+    )?;
+
+    let invite = &mut ctx.accounts.invite;
+    invite.payroll = ctx.accounts.payroll.key();
+    invite.employee_id = employee_id.clone();
+    invite.name = name.clone();
+    invite.wallet = wallet;
+    invite.salary_commitment = salary_commitment;
+    invite.created_by = ctx.accounts.hr_admin.key();
+    invite.created_at = clock.unix_timestamp;
+    invite.prefunded_lamports = prefunded_lamports;
+    invite.bump = ctx.bumps.invite;
+
+    emit!(EmployeeInvited {
+        payroll: invite.payroll,
+        invite: invite.key(),
+        employee_id: employee_id.clone(),
+        wallet,
+        created_by: invite.created_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Employee invited: {} ({})", name, employee_id);
+    msg!("Invite: {} (wallet {})", invite.key(), wallet);
+
+    Ok(())
+}
@@ -1,18 +1,31 @@
 use anchor_lang::prelude::*;
-use crate::state::{Payroll, Employee};
+use crate::state::{Payroll, Employee, Role, RoleRegistry};
 use crate::errors::PayrollError;
+use crate::events::EmployeeDeactivated;
 
 #[derive(Accounts)]
 pub struct DeactivateEmployee<'info> {
     /// Payroll account
     #[account(
         mut,
-        seeds = [b"payroll", authority.key().as_ref()],
-        bump = payroll.bump,
-        has_one = authority @ PayrollError::Unauthorized
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
     )]
     pub payroll: Account<'info, Payroll>,
 
+    /// Role registry (signer must hold the HR admin role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
     /// Employee to deactivate
     #[account(
         mut,
@@ -21,19 +34,28 @@ pub struct DeactivateEmployee<'info> {
     )]
     pub employee: Account<'info, Employee>,
 
-    /// Authority (must match payroll.authority)
-    pub authority: Signer<'info>,
+    /// HR admin (payroll authority or role holder)
+    pub hr_admin: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<DeactivateEmployee>) -> Result<()> {
+    let clock = Clock::get()?;
     let employee = &mut ctx.accounts.employee;
     let payroll = &mut ctx.accounts.payroll;
 
     employee.is_active = false;
+    employee.deactivated_at = clock.unix_timestamp;
 
     // Update payroll stats
     payroll.employee_count = payroll.employee_count.saturating_sub(1);
 
+    emit!(EmployeeDeactivated {
+        payroll: payroll.key(),
+        employee: employee.key(),
+        employee_id: employee.employee_id.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Employee deactivated: {} ({})", employee.name, employee.employee_id);
 
     Ok(())
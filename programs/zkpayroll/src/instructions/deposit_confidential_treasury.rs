@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::{Payroll, Role, RoleRegistry};
+use crate::errors::{PayrollError, AE_CIPHERTEXT_LEN};
+use crate::events::ConfidentialTreasuryDeposited;
+use crate::instructions::process_confidential_payment::ConfidentialTreasury;
+
+#[derive(Accounts)]
+pub struct DepositConfidentialTreasury<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Payroll confidential treasury vault
+    #[account(
+        mut,
+        seeds = [b"confidential_treasury", payroll.key().as_ref()],
+        bump = payroll.confidential_treasury_bump,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub confidential_treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint held by the confidential treasury vault
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Payroll operator (payroll authority or role holder)
+    pub operator: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<DepositConfidentialTreasury>,
+    amount: u64,
+    expected_pending_balance_credit_counter: u64,
+    new_decryptable_available_balance: [u8; AE_CIPHERTEXT_LEN],
+) -> Result<()> {
+    // Tokens reach the vault's public balance through ordinary transfers;
+    // the payroll PDA moves them into the confidential balance, then credits
+    // the pending balance (including incoming confidential transfers) so
+    // payments can spend it. A zero amount only applies the pending balance.
+    let treasury = ConfidentialTreasury {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        vault: ctx.accounts.confidential_treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+    };
+    if amount > 0 {
+        treasury.deposit(amount, ctx.accounts.mint.decimals)?;
+    }
+    treasury.apply_pending_balance(
+        expected_pending_balance_credit_counter,
+        new_decryptable_available_balance,
+    )?;
+
+    emit!(ConfidentialTreasuryDeposited {
+        payroll: ctx.accounts.payroll.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Deposited {} into confidential treasury {}", amount, ctx.accounts.confidential_treasury.key());
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::TreasuryDeposited;
+
+#[derive(Accounts)]
+pub struct DepositTreasury<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Payroll treasury vault (receives the deposit)
+    #[account(
+        mut,
+        seeds = [b"treasury", payroll.key().as_ref()],
+        bump = payroll.treasury_bump,
+        token::mint = mint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// Mint of the token salaries are paid in
+    pub mint: Account<'info, Mint>,
+
+    /// Depositor's token account (source of funds)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    /// Anyone may fund the treasury
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<DepositTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, PayrollError::InvalidAmount);
+
+    token::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(TreasuryDeposited {
+        payroll: ctx.accounts.payroll.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Deposited {} into treasury {}", amount, ctx.accounts.treasury.key());
+
+    Ok(())
+}
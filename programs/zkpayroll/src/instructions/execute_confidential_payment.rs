@@ -0,0 +1,250 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::{
+    Payroll, Employee, PaymentProposal, PaymentRecord, PayrollRun, Role, RoleRegistry,
+    SalaryHistory,
+};
+use crate::errors::{PayrollError, AE_CIPHERTEXT_LEN};
+use crate::events::PaymentProcessed;
+use crate::instructions::process_payment::{check_salary_commitment, track_run};
+use crate::instructions::process_confidential_payment::{
+    check_transfer_commitment, ConfidentialTreasury,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteConfidentialPayment<'info> {
+    /// Payroll configuration
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.approval_policy.is_some() @ PayrollError::NoApprovalPolicy
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Employee receiving payment
+    #[account(
+        mut,
+        constraint = employee.payroll == payroll.key() @ PayrollError::Unauthorized,
+        constraint = employee.is_active @ PayrollError::EmployeeNotActive
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// Employee's salary history (commitment in force for the period)
+    #[account(
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump = salary_history.bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
+    /// Approved confidential payment proposal (closed on execution)
+    #[account(
+        mut,
+        close = proposer,
+        has_one = payroll @ PayrollError::Unauthorized,
+        has_one = employee @ PayrollError::Unauthorized,
+        has_one = proposer @ PayrollError::Unauthorized,
+        constraint = proposal.confidential @ PayrollError::ProposalTypeMismatch
+    )]
+    pub proposal: Box<Account<'info, PaymentProposal>>,
+
+    /// Payment record (created for this payment)
+    #[account(
+        init,
+        payer = operator,
+        space = PaymentRecord::LEN,
+        seeds = [
+            b"payment",
+            employee.key().as_ref(),
+            &proposal.period.to_le_bytes(),
+            &proposal.payment_type.record_index().to_le_bytes()
+        ],
+        bump
+    )]
+    pub payment_record: Account<'info, PaymentRecord>,
+
+    /// Payroll run the payment counts towards (required while one is open)
+    #[account(
+        mut,
+        has_one = payroll @ PayrollError::Unauthorized
+    )]
+    pub payroll_run: Option<Box<Account<'info, PayrollRun>>>,
+
+    /// Payroll operator (payroll authority or role holder)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Operator who created the proposal (receives its rent)
+    /// CHECK: Must match proposal.proposer
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Token-2022 mint with the confidential transfer extension
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Payroll confidential treasury vault (source of funds)
+    #[account(
+        mut,
+        seeds = [b"confidential_treasury", payroll.key().as_ref()],
+        bump = payroll.confidential_treasury_bump,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub confidential_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Employee's confidential token account (receives net salary)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = employee_token_account.owner == employee.wallet
+            @ PayrollError::InvalidTokenAccount
+    )]
+    pub employee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Tax authority's confidential token account (receives withheld tax)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = tax_token_account.owner == payroll.tax_authority
+            @ PayrollError::InvalidTokenAccount
+    )]
+    pub tax_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Pre-verified transfer proof context for the net salary transfer
+    /// CHECK: Owner and proof type are checked here; the ciphertexts are
+    /// validated by Token-2022
+    pub salary_proof_context: UncheckedAccount<'info>,
+
+    /// Pre-verified transfer proof context for the tax transfer
+    /// CHECK: Owner and proof type are checked here; the ciphertexts are
+    /// validated by Token-2022
+    pub tax_proof_context: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ExecuteConfidentialPayment>,
+    salary_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+    tax_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let payroll = &ctx.accounts.payroll;
+    let proposal = &ctx.accounts.proposal;
+
+    // One regular payment per period; off-cycle indexes never repeat
+    ctx.accounts.employee.record_payment(proposal.period, proposal.payment_type)?;
+    let employee = &ctx.accounts.employee;
+
+    // Approvals only count while the approver is still in the policy
+    let policy = payroll.approval_policy.as_ref().unwrap();
+    let threshold = policy.threshold;
+    require!(
+        policy.count_approvals(&proposal.approvals) >= threshold as usize,
+        PayrollError::InsufficientApprovals
+    );
+
+    // The transfers must move exactly the approved commitments, which must
+    // still add up to the committed salary in case HR changed it since
+    check_transfer_commitment(&ctx.accounts.salary_proof_context, &proposal.commitments.net)?;
+    check_transfer_commitment(&ctx.accounts.tax_proof_context, &proposal.commitments.tax)?;
+    check_salary_commitment(
+        &ctx.accounts.salary_history,
+        proposal.period,
+        proposal.payment_type,
+        &proposal.commitments,
+    )?;
+
+    // Check screening is still valid at execution time
+    let time_since_screening = payroll.check_screening(employee, clock.unix_timestamp)?;
+
+    msg!("Executing approved confidential payment for employee: {}", employee.employee_id);
+    msg!("Screening valid (last screened {} seconds ago)", time_since_screening);
+
+    let treasury = ConfidentialTreasury {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        vault: ctx.accounts.confidential_treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+    };
+    treasury.transfer(
+        ctx.accounts.employee_token_account.to_account_info(),
+        ctx.accounts.salary_proof_context.to_account_info(),
+        salary_new_decryptable_balance,
+    )?;
+    treasury.transfer(
+        ctx.accounts.tax_token_account.to_account_info(),
+        ctx.accounts.tax_proof_context.to_account_info(),
+        tax_new_decryptable_balance,
+    )?;
+
+    // Count the payment against its payroll run, if any (paid from the
+    // confidential treasury, so the treasury vault reservation is untouched)
+    let run = track_run(
+        &mut ctx.accounts.payroll,
+        ctx.accounts.payroll_run.as_deref_mut(),
+        proposal.period,
+        proposal.payment_type,
+        1,
+        None,
+        clock.unix_timestamp,
+    )?;
+
+    // Create payment record (NO amounts stored for privacy!)
+    let payment_record = &mut ctx.accounts.payment_record;
+    payment_record.payroll = ctx.accounts.payroll.key();
+    payment_record.employee = employee.key();
+    payment_record.timestamp = clock.unix_timestamp;
+    payment_record.period = proposal.period;
+    payment_record.payment_type = proposal.payment_type;
+    payment_record.run = run;
+    payment_record.commitments = proposal.commitments;
+    payment_record.tax_rate_bps = ctx.accounts.payroll.tax_rate_bps;
+    payment_record.tax_confidential_account = ctx.accounts.tax_token_account.key();
+    payment_record.employee_confidential_account = ctx.accounts.employee_token_account.key();
+    payment_record.auditors = ctx.accounts.payroll.auditors.clone();
+    payment_record.verified = false;
+    payment_record.verified_at = 0;
+    payment_record.verifier = Pubkey::default();
+    payment_record.bump = ctx.bumps.payment_record;
+
+    emit!(PaymentProcessed {
+        payroll: ctx.accounts.payroll.key(),
+        employee: employee.key(),
+        payment_record: payment_record.key(),
+        tax_confidential_account: payment_record.tax_confidential_account,
+        employee_confidential_account: payment_record.employee_confidential_account,
+        period: proposal.period,
+        payment_type: proposal.payment_type,
+        run,
+        confidential: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payment record created: {}", payment_record.key());
+    msg!("Approvals: {}/{}", proposal.approvals.len(), threshold);
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{Payroll, PayrollRun, Role, RoleRegistry, RunStatus};
+use crate::errors::PayrollError;
+use crate::events::PayrollRunFunded;
+
+#[derive(Accounts)]
+pub struct FundPayrollRun<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Open payroll run to mark as funded
+    #[account(
+        mut,
+        has_one = payroll @ PayrollError::Unauthorized,
+        constraint = payroll_run.status == RunStatus::Open @ PayrollError::InvalidRunStatus,
+        constraint = payroll.active_run == Some(payroll_run.key()) @ PayrollError::InvalidRunStatus
+    )]
+    pub payroll_run: Account<'info, PayrollRun>,
+
+    /// Payroll treasury vault the run pays from
+    #[account(
+        seeds = [b"treasury", payroll.key().as_ref()],
+        bump = payroll.treasury_bump
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// Payroll operator (payroll authority or role holder)
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<FundPayrollRun>, funded_amount: u64) -> Result<()> {
+    require!(funded_amount > 0, PayrollError::InvalidAmount);
+    require!(
+        ctx.accounts.treasury.amount >= funded_amount,
+        PayrollError::InsufficientBalance
+    );
+
+    let clock = Clock::get()?;
+    let payroll_run = &mut ctx.accounts.payroll_run;
+
+    payroll_run.funded_amount = funded_amount;
+    payroll_run.status = RunStatus::Funded;
+
+    // Reserve the funds: withdrawals cannot dip below what the run still owes
+    ctx.accounts.payroll.reserved_amount = funded_amount;
+
+    emit!(PayrollRunFunded {
+        payroll: payroll_run.payroll,
+        run: payroll_run.key(),
+        funded_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payroll run for period {} funded", payroll_run.period);
+    msg!("Treasury funds reserved: {}", funded_amount);
+
+    Ok(())
+}
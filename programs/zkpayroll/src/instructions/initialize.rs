@@ -1,19 +1,35 @@
 use anchor_lang::prelude::*;
-use crate::state::Payroll;
-use crate::errors::{PayrollError, MAX_TAX_RATE_BPS};
+use crate::state::{Payroll, RoleRegistry};
+use crate::errors::{
+    PayrollError, DEFAULT_MIN_PERIOD_SECONDS, DEFAULT_SCREENING_THRESHOLD,
+    DEFAULT_SCREENING_VALIDITY_SECONDS, MAX_CONFIG_DELAY_SECONDS, MAX_TAX_RATE_BPS,
+};
+use crate::events::PayrollInitialized;
+use crate::shadowwire;
 
 #[derive(Accounts)]
+#[instruction(payroll_id: u64)]
 pub struct Initialize<'info> {
     /// Payroll account to initialize (PDA)
     #[account(
         init,
         payer = authority,
         space = Payroll::LEN,
-        seeds = [b"payroll", authority.key().as_ref()],
+        seeds = [b"payroll", authority.key().as_ref(), payroll_id.to_le_bytes().as_ref()],
         bump
     )]
     pub payroll: Account<'info, Payroll>,
 
+    /// Role registry for delegated payroll roles (PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = RoleRegistry::LEN,
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
     /// Employer/authority who owns this payroll
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -26,12 +42,25 @@ pub struct Initialize<'info> {
     /// CHECK: This is the ShadowWire program ID, validated by caller
     pub shadowwire_program: UncheckedAccount<'info>,
 
+    /// Tax authority's ShadowWire confidential account (receives withheld tax)
+    /// CHECK: Must be a ShadowWire confidential account of the tax authority
+    #[account(
+        constraint = shadowwire::is_confidential_account_of(
+            &tax_confidential_account,
+            &shadowwire_program.key(),
+            &tax_authority.key()
+        ) @ PayrollError::InvalidConfidentialAccount
+    )]
+    pub tax_confidential_account: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<Initialize>,
+    payroll_id: u64,
     tax_rate_bps: u16,
+    config_delay_seconds: i64,
 ) -> Result<()> {
     // Validate tax rate
     require!(
@@ -39,15 +68,60 @@ pub fn handler(
         PayrollError::InvalidTaxRate
     );
 
+    // Validate config change notice period
+    require!(
+        (0..=MAX_CONFIG_DELAY_SECONDS).contains(&config_delay_seconds),
+        PayrollError::InvalidConfigDelay
+    );
+
     let payroll = &mut ctx.accounts.payroll;
     
+    payroll.creator = ctx.accounts.authority.key();
+    payroll.payroll_id = payroll_id;
     payroll.authority = ctx.accounts.authority.key();
+    payroll.pending_authority = None;
     payroll.tax_authority = ctx.accounts.tax_authority.key();
+    payroll.tax_confidential_account = ctx.accounts.tax_confidential_account.key();
     payroll.tax_rate_bps = tax_rate_bps;
     payroll.shadowwire_program = ctx.accounts.shadowwire_program.key();
     payroll.bump = ctx.bumps.payroll;
     payroll.employee_count = 0;
     payroll.payment_count = 0;
+    payroll.config_delay_seconds = config_delay_seconds;
+    payroll.pending_config = None;
+    payroll.approval_policy = None;
+    payroll.screening_threshold = DEFAULT_SCREENING_THRESHOLD;
+    payroll.screening_validity_seconds = DEFAULT_SCREENING_VALIDITY_SECONDS;
+    payroll.current_period = 1;
+    payroll.period_started_at = Clock::get()?.unix_timestamp;
+    payroll.min_period_seconds = DEFAULT_MIN_PERIOD_SECONDS;
+    payroll.treasury_mint = Pubkey::default();
+    payroll.treasury_bump = 0;
+    payroll.auditors = Vec::new();
+    payroll.active_run = None;
+    payroll.reserved_amount = 0;
+    payroll.confidential_mint = Pubkey::default();
+    payroll.confidential_treasury_bump = 0;
+    payroll.run_count = 0;
+
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.payroll = payroll.key();
+    role_registry.members = Vec::new();
+    role_registry.bump = ctx.bumps.role_registry;
+
+    emit!(PayrollInitialized {
+        payroll: payroll.key(),
+        payroll_id,
+        authority: payroll.authority,
+        tax_authority: payroll.tax_authority,
+        tax_confidential_account: payroll.tax_confidential_account,
+        tax_rate_bps,
+        shadowwire_program: payroll.shadowwire_program,
+        config_delay_seconds,
+        screening_threshold: payroll.screening_threshold,
+        screening_validity_seconds: payroll.screening_validity_seconds,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!("Payroll initialized with {}% tax rate", tax_rate_bps as f64 / 100.0);
     msg!("Tax authority: {}", payroll.tax_authority);
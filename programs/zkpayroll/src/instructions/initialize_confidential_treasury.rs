@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        self,
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    },
+    InitializeAccount3, Token2022,
+};
+use anchor_spl::token_interface::Mint;
+use crate::state::Payroll;
+use crate::errors::{PayrollError, AE_CIPHERTEXT_LEN};
+use crate::events::ConfidentialTreasuryInitialized;
+use crate::instructions::process_confidential_payment::ConfidentialTreasury;
+
+#[derive(Accounts)]
+pub struct InitializeConfidentialTreasury<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized,
+        constraint = payroll.confidential_mint == Pubkey::default()
+            @ PayrollError::TreasuryAlreadyInitialized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Confidential treasury vault to create (Token-2022 account PDA owned
+    /// by the payroll)
+    /// CHECK: Created and initialized here with the confidential transfer extension
+    #[account(
+        mut,
+        seeds = [b"confidential_treasury", payroll.key().as_ref()],
+        bump
+    )]
+    pub confidential_treasury: UncheckedAccount<'info>,
+
+    /// Token-2022 mint with the confidential transfer extension
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Pre-verified pubkey validity proof context for the vault's ElGamal key
+    /// CHECK: Owner, proof type and public key are validated by Token-2022
+    pub pubkey_proof_context: UncheckedAccount<'info>,
+
+    /// Authority (must match payroll.authority)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeConfidentialTreasury>,
+    decryptable_zero_balance: [u8; AE_CIPHERTEXT_LEN],
+    maximum_pending_balance_credit_counter: u64,
+) -> Result<()> {
+    // Size the vault for the confidential transfer extension plus any
+    // extensions the mint requires on its token accounts
+    let space = {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        let mut extensions =
+            ExtensionType::get_required_init_account_extensions(&mint.get_extension_types()?);
+        extensions.push(ExtensionType::ConfidentialTransferAccount);
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&extensions)?
+    };
+
+    let payroll_key = ctx.accounts.payroll.key();
+    let treasury_bump = ctx.bumps.confidential_treasury;
+    let treasury_seeds: &[&[&[u8]]] =
+        &[&[b"confidential_treasury", payroll_key.as_ref(), &[treasury_bump]]];
+
+    create_treasury_account(&ctx, space, treasury_seeds)?;
+
+    token_2022::initialize_account3(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        InitializeAccount3 {
+            account: ctx.accounts.confidential_treasury.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.payroll.to_account_info(),
+        },
+    ))?;
+
+    let treasury = ConfidentialTreasury {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        vault: ctx.accounts.confidential_treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+    };
+    treasury.configure(
+        ctx.accounts.pubkey_proof_context.to_account_info(),
+        decryptable_zero_balance,
+        maximum_pending_balance_credit_counter,
+    )?;
+
+    let payroll = &mut ctx.accounts.payroll;
+    payroll.confidential_mint = ctx.accounts.mint.key();
+    payroll.confidential_treasury_bump = treasury_bump;
+
+    emit!(ConfidentialTreasuryInitialized {
+        payroll: payroll.key(),
+        treasury: ctx.accounts.confidential_treasury.key(),
+        mint: payroll.confidential_mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Confidential treasury vault created: {}", ctx.accounts.confidential_treasury.key());
+    msg!("Mint: {}", payroll.confidential_mint);
+
+    Ok(())
+}
+
+/// Create the vault account owned by Token-2022
+///
+/// The vault address is deterministic, so anyone can send it lamports
+/// first; `create_account` would then fail forever. Top up the rent
+/// shortfall and allocate/assign instead in that case.
+fn create_treasury_account(
+    ctx: &Context<InitializeConfidentialTreasury>,
+    space: usize,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let authority = ctx.accounts.authority.to_account_info();
+    let vault = ctx.accounts.confidential_treasury.to_account_info();
+    let rent = Rent::get()?.minimum_balance(space);
+
+    let current_lamports = vault.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: authority,
+                    to: vault,
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &token_2022::ID,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: authority,
+                    to: vault.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: vault.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: vault,
+            },
+            signer,
+        ),
+        &token_2022::ID,
+    )
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::TreasuryInitialized;
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized,
+        constraint = payroll.treasury_mint == Pubkey::default()
+            @ PayrollError::TreasuryAlreadyInitialized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Treasury vault to create (token account PDA owned by the payroll)
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"treasury", payroll.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = payroll
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// Mint of the token salaries are paid in
+    pub mint: Account<'info, Mint>,
+
+    /// Authority (must match payroll.authority)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeTreasury>) -> Result<()> {
+    let payroll = &mut ctx.accounts.payroll;
+
+    payroll.treasury_mint = ctx.accounts.mint.key();
+    payroll.treasury_bump = ctx.bumps.treasury;
+
+    emit!(TreasuryInitialized {
+        payroll: payroll.key(),
+        treasury: ctx.accounts.treasury.key(),
+        mint: payroll.treasury_mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Treasury vault created: {}", ctx.accounts.treasury.key());
+    msg!("Mint: {}", payroll.treasury_mint);
+
+    Ok(())
+}
@@ -1,13 +1,96 @@
 pub mod initialize;
+pub mod initialize_treasury;
+pub mod deposit_treasury;
+pub mod withdraw_treasury;
+pub mod initialize_confidential_treasury;
+pub mod deposit_confidential_treasury;
+pub mod withdraw_confidential_treasury;
 pub mod add_employee;
+pub mod create_employee_invite;
+pub mod accept_employee_invite;
+pub mod cancel_employee_invite;
 pub mod process_payment;
+pub mod process_payroll_batch;
+pub mod process_confidential_payment;
+pub mod record_payment_audit;
 pub mod verify_proof;
 pub mod update_screening;
+pub mod set_screening_policy;
+pub mod register_screening_provider;
+pub mod remove_screening_provider;
+pub mod add_auditor;
+pub mod remove_auditor;
 pub mod deactivate_employee;
+pub mod reactivate_employee;
+pub mod update_salary_commitment;
+pub mod update_employee_wallet;
+pub mod close_employee;
+pub mod close_payment_record;
+pub mod advance_pay_period;
+pub mod open_payroll_run;
+pub mod fund_payroll_run;
+pub mod cancel_payroll_run;
+pub mod update_payroll_config;
+pub mod apply_payroll_config;
+pub mod cancel_payroll_config;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_approval_policy;
+pub mod propose_payment;
+pub mod approve_payment;
+pub mod execute_payment;
+pub mod propose_confidential_payment;
+pub mod execute_confidential_payment;
+pub mod cancel_payment;
 
+// Every instruction module exposes its own `handler`; lib.rs always calls them
+// by full path, so the overlapping glob re-exports are intentional.
+#[allow(ambiguous_glob_reexports)]
 pub use initialize::*;
+pub use initialize_treasury::*;
+pub use deposit_treasury::*;
+pub use withdraw_treasury::*;
+pub use initialize_confidential_treasury::*;
+pub use deposit_confidential_treasury::*;
+pub use withdraw_confidential_treasury::*;
 pub use add_employee::*;
+pub use create_employee_invite::*;
+pub use accept_employee_invite::*;
+pub use cancel_employee_invite::*;
 pub use process_payment::*;
+pub use process_payroll_batch::*;
+pub use process_confidential_payment::*;
+pub use record_payment_audit::*;
 pub use verify_proof::*;
 pub use update_screening::*;
+pub use set_screening_policy::*;
+pub use register_screening_provider::*;
+pub use remove_screening_provider::*;
+pub use add_auditor::*;
+pub use remove_auditor::*;
 pub use deactivate_employee::*;
+pub use reactivate_employee::*;
+pub use update_salary_commitment::*;
+pub use update_employee_wallet::*;
+pub use close_employee::*;
+pub use close_payment_record::*;
+pub use advance_pay_period::*;
+pub use open_payroll_run::*;
+pub use fund_payroll_run::*;
+pub use cancel_payroll_run::*;
+pub use update_payroll_config::*;
+pub use apply_payroll_config::*;
+pub use cancel_payroll_config::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_approval_policy::*;
+pub use propose_payment::*;
+pub use approve_payment::*;
+pub use execute_payment::*;
+pub use propose_confidential_payment::*;
+pub use execute_confidential_payment::*;
+pub use cancel_payment::*;
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, PayrollRun, Role, RoleRegistry, RunStatus};
+use crate::errors::PayrollError;
+use crate::events::PayrollRunOpened;
+
+#[derive(Accounts)]
+pub struct OpenPayrollRun<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.active_run.is_none() @ PayrollError::RunInProgress
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Payroll run for the current pay period (PDA, one per run opened)
+    #[account(
+        init,
+        payer = operator,
+        space = PayrollRun::LEN,
+        seeds = [
+            b"run",
+            payroll.key().as_ref(),
+            &payroll.run_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub payroll_run: Account<'info, PayrollRun>,
+
+    /// Payroll operator (payroll authority or role holder)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenPayrollRun>, expected_count: u32) -> Result<()> {
+    require!(expected_count > 0, PayrollError::InvalidRunSize);
+
+    let clock = Clock::get()?;
+    let payroll_run = &mut ctx.accounts.payroll_run;
+
+    payroll_run.payroll = ctx.accounts.payroll.key();
+    payroll_run.period = ctx.accounts.payroll.current_period;
+    payroll_run.status = RunStatus::Open;
+    payroll_run.expected_count = expected_count;
+    payroll_run.processed_count = 0;
+    payroll_run.off_cycle_count = 0;
+    payroll_run.funded_amount = 0;
+    payroll_run.opened_by = ctx.accounts.operator.key();
+    payroll_run.opened_at = clock.unix_timestamp;
+    payroll_run.completed_at = 0;
+    payroll_run.cancelled_at = 0;
+    payroll_run.bump = ctx.bumps.payroll_run;
+
+    // Payments for the period now have to go through this run
+    let payroll = &mut ctx.accounts.payroll;
+    payroll.active_run = Some(payroll_run.key());
+    payroll.run_count = payroll.run_count.checked_add(1).unwrap();
+
+    emit!(PayrollRunOpened {
+        payroll: payroll_run.payroll,
+        run: payroll_run.key(),
+        period: payroll_run.period,
+        expected_count,
+        opened_by: payroll_run.opened_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payroll run opened for period {}", payroll_run.period);
+    msg!("Expected payments: {}", expected_count);
+
+    Ok(())
+}
@@ -0,0 +1,419 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::confidential_transfer::instruction::{
+        deposit, inner_apply_pending_balance, inner_configure_account, inner_transfer,
+        inner_withdraw,
+    },
+    proof::ProofLocation,
+    solana_zk_token_sdk::{
+        encryption::auth_encryption::AeCiphertext as DecodedAeCiphertext,
+        instruction::TransferProofContext,
+        zk_token_elgamal::pod::AeCiphertext,
+        zk_token_proof_instruction::ProofType,
+    },
+};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::{
+    Payroll, Employee, PaymentCommitments, PaymentRecord, PaymentType, PayrollRun, Role,
+    RoleRegistry, SalaryHistory,
+};
+use crate::errors::{PayrollError, AE_CIPHERTEXT_LEN};
+use crate::events::PaymentProcessed;
+use crate::proof;
+use crate::instructions::process_payment::{
+    check_off_cycle_sign_off, check_salary_commitment, track_run,
+};
+
+#[derive(Accounts)]
+#[instruction(payment_type: PaymentType)]
+pub struct ProcessConfidentialPayment<'info> {
+    /// Payroll configuration
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.approval_policy.is_none() @ PayrollError::ApprovalRequired
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Employee receiving payment
+    #[account(
+        mut,
+        constraint = employee.payroll == payroll.key() @ PayrollError::Unauthorized,
+        constraint = employee.is_active @ PayrollError::EmployeeNotActive
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// Employee's salary history (commitment in force for the period)
+    #[account(
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump = salary_history.bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
+    /// Payment record (created for this payment)
+    #[account(
+        init,
+        payer = operator,
+        space = PaymentRecord::LEN,
+        seeds = [
+            b"payment",
+            employee.key().as_ref(),
+            &payroll.current_period.to_le_bytes(),
+            &payment_type.record_index().to_le_bytes()
+        ],
+        bump
+    )]
+    pub payment_record: Account<'info, PaymentRecord>,
+
+    /// Payroll run the payment counts towards (required while one is open)
+    #[account(
+        mut,
+        has_one = payroll @ PayrollError::Unauthorized
+    )]
+    pub payroll_run: Option<Account<'info, PayrollRun>>,
+
+    /// Payroll operator (payroll authority or role holder)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// HR admin signing off an off-cycle payment (required for off-cycle
+    /// payments, must not be the operator)
+    #[account(
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub hr_admin: Option<Signer<'info>>,
+
+    /// Token-2022 mint with the confidential transfer extension
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Payroll confidential treasury vault (source of funds)
+    #[account(
+        mut,
+        seeds = [b"confidential_treasury", payroll.key().as_ref()],
+        bump = payroll.confidential_treasury_bump,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub confidential_treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Employee's confidential token account (receives net salary)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = employee_token_account.owner == employee.wallet
+            @ PayrollError::InvalidTokenAccount
+    )]
+    pub employee_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Tax authority's confidential token account (receives withheld tax)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = tax_token_account.owner == payroll.tax_authority
+            @ PayrollError::InvalidTokenAccount
+    )]
+    pub tax_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Pre-verified transfer proof context for the net salary transfer
+    /// CHECK: Owner and proof type are checked here; the ciphertexts are
+    /// validated by Token-2022
+    pub salary_proof_context: UncheckedAccount<'info>,
+
+    /// Pre-verified transfer proof context for the tax transfer
+    /// CHECK: Owner and proof type are checked here; the ciphertexts are
+    /// validated by Token-2022
+    pub tax_proof_context: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProcessConfidentialPayment>,
+    payment_type: PaymentType,
+    commitments: PaymentCommitments,
+    salary_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+    tax_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let period = ctx.accounts.payroll.current_period;
+
+    // One regular payment per period; off-cycle indexes never repeat
+    ctx.accounts.employee.record_payment(period, payment_type)?;
+    let employee = &ctx.accounts.employee;
+    let payroll = &ctx.accounts.payroll;
+
+    // Check screening passes the payroll's threshold and has not expired
+    let time_since_screening = payroll.check_screening(employee, clock.unix_timestamp)?;
+
+    // The transfers must move exactly the committed amounts, and the hidden
+    // amounts must still add up to the committed salary
+    check_transfer_commitment(&ctx.accounts.salary_proof_context, &commitments.net)?;
+    check_transfer_commitment(&ctx.accounts.tax_proof_context, &commitments.tax)?;
+    check_salary_commitment(&ctx.accounts.salary_history, period, payment_type, &commitments)?;
+    check_off_cycle_sign_off(
+        payment_type,
+        &ctx.accounts.operator,
+        ctx.accounts.hr_admin.as_ref(),
+    )?;
+
+    msg!("Processing confidential payment for employee: {}", employee.employee_id);
+    msg!("Pay period: {} ({:?})", period, payment_type);
+    msg!("Screening valid (last screened {} seconds ago)", time_since_screening);
+
+    // ============================================================
+    // TOKEN-2022 CONFIDENTIAL TRANSFERS
+    // ============================================================
+    //
+    // Amounts never appear in this instruction. The client encrypts them,
+    // verifies the transfer proofs into context state accounts with the
+    // ZK Token Proof program, and passes the treasury's new decryptable
+    // balance after each transfer so the account stays decryptable.
+    // The payroll PDA signs for its confidential treasury vault.
+    //
+    // Step 1: Transfer net salary from treasury vault -> employee
+    // Step 2: Transfer tax from treasury vault -> tax_authority
+    // ============================================================
+
+    let treasury = ConfidentialTreasury {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        vault: ctx.accounts.confidential_treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+    };
+    treasury.transfer(
+        ctx.accounts.employee_token_account.to_account_info(),
+        ctx.accounts.salary_proof_context.to_account_info(),
+        salary_new_decryptable_balance,
+    )?;
+    treasury.transfer(
+        ctx.accounts.tax_token_account.to_account_info(),
+        ctx.accounts.tax_proof_context.to_account_info(),
+        tax_new_decryptable_balance,
+    )?;
+
+    // Count the payment against its payroll run, if any
+    // Paid from the confidential treasury, which stays locked while the run
+    // is active, so the treasury vault reservation is left untouched
+    let run = track_run(
+        &mut ctx.accounts.payroll,
+        ctx.accounts.payroll_run.as_mut(),
+        period,
+        payment_type,
+        1,
+        None,
+        clock.unix_timestamp,
+    )?;
+
+    // Create payment record (NO amounts stored for privacy!)
+    let payment_record = &mut ctx.accounts.payment_record;
+    payment_record.payroll = ctx.accounts.payroll.key();
+    payment_record.employee = employee.key();
+    payment_record.timestamp = clock.unix_timestamp;
+    payment_record.period = period;
+    payment_record.payment_type = payment_type;
+    payment_record.run = run;
+    payment_record.commitments = commitments;
+    payment_record.tax_rate_bps = ctx.accounts.payroll.tax_rate_bps;
+    payment_record.tax_confidential_account = ctx.accounts.tax_token_account.key();
+    payment_record.employee_confidential_account = ctx.accounts.employee_token_account.key();
+    payment_record.auditors = ctx.accounts.payroll.auditors.clone();
+    payment_record.verified = false;
+    payment_record.verified_at = 0;
+    payment_record.verifier = Pubkey::default();
+    payment_record.bump = ctx.bumps.payment_record;
+
+    emit!(PaymentProcessed {
+        payroll: ctx.accounts.payroll.key(),
+        employee: employee.key(),
+        payment_record: payment_record.key(),
+        tax_confidential_account: payment_record.tax_confidential_account,
+        employee_confidential_account: payment_record.employee_confidential_account,
+        period,
+        payment_type,
+        run,
+        confidential: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payment record created: {}", payment_record.key());
+    msg!("Tax sent to: {}", payment_record.tax_confidential_account);
+    msg!("Net salary sent to: {}", payment_record.employee_confidential_account);
+    msg!("IMPORTANT: Actual amounts are encrypted via Token-2022 confidential transfers");
+
+    Ok(())
+}
+
+/// Check the transfer verified into `proof_context` moves the amount
+/// committed to by `commitment`
+pub(crate) fn check_transfer_commitment(
+    proof_context: &UncheckedAccount,
+    commitment: &[u8; 32],
+) -> Result<()> {
+    let context: TransferProofContext =
+        proof::load_proof_context(proof_context, ProofType::Transfer)?;
+    require!(
+        proof::transfer_commits_to(&context, commitment),
+        PayrollError::CommitmentMismatch
+    );
+    Ok(())
+}
+
+/// Token-2022 confidential transfer CPIs on the confidential treasury
+/// vault, signed by the payroll PDA that owns it
+pub(crate) struct ConfidentialTreasury<'info> {
+    pub token_program: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub vault: AccountInfo<'info>,
+    pub payroll: AccountInfo<'info>,
+    pub creator: Pubkey,
+    pub payroll_id: u64,
+    pub payroll_bump: u8,
+}
+
+impl<'info> ConfidentialTreasury<'info> {
+    /// Enable confidential transfers on the vault using a pre-verified
+    /// pubkey validity proof context for its ElGamal key
+    pub fn configure(
+        &self,
+        proof_context: AccountInfo<'info>,
+        decryptable_zero_balance: [u8; AE_CIPHERTEXT_LEN],
+        maximum_pending_balance_credit_counter: u64,
+    ) -> Result<()> {
+        let ix = inner_configure_account(
+            self.token_program.key,
+            self.vault.key,
+            self.mint.key,
+            DecodedAeCiphertext::from_bytes(&decryptable_zero_balance)
+                .ok_or(PayrollError::InvalidCiphertext)?,
+            maximum_pending_balance_credit_counter,
+            self.payroll.key,
+            &[],
+            ProofLocation::ContextStateAccount(proof_context.key),
+        )?;
+        self.invoke(
+            &ix,
+            &[self.vault.clone(), self.mint.clone(), proof_context, self.payroll.clone()],
+        )
+    }
+
+    /// Move `amount` of the vault's public balance into its pending balance
+    pub fn deposit(&self, amount: u64, decimals: u8) -> Result<()> {
+        let ix = deposit(
+            self.token_program.key,
+            self.vault.key,
+            self.mint.key,
+            amount,
+            decimals,
+            self.payroll.key,
+            &[],
+        )?;
+        self.invoke(&ix, &[self.vault.clone(), self.mint.clone(), self.payroll.clone()])
+    }
+
+    /// Credit the vault's pending balance to its available balance
+    pub fn apply_pending_balance(
+        &self,
+        expected_pending_balance_credit_counter: u64,
+        new_decryptable_available_balance: [u8; AE_CIPHERTEXT_LEN],
+    ) -> Result<()> {
+        let ix = inner_apply_pending_balance(
+            self.token_program.key,
+            self.vault.key,
+            expected_pending_balance_credit_counter,
+            AeCiphertext(new_decryptable_available_balance),
+            self.payroll.key,
+            &[],
+        )?;
+        self.invoke(&ix, &[self.vault.clone(), self.payroll.clone()])
+    }
+
+    /// Move `amount` of the vault's available balance back to its public
+    /// balance using a pre-verified withdraw proof context
+    pub fn withdraw(
+        &self,
+        proof_context: AccountInfo<'info>,
+        amount: u64,
+        decimals: u8,
+        new_decryptable_available_balance: [u8; AE_CIPHERTEXT_LEN],
+    ) -> Result<()> {
+        let ix = inner_withdraw(
+            self.token_program.key,
+            self.vault.key,
+            self.mint.key,
+            amount,
+            decimals,
+            AeCiphertext(new_decryptable_available_balance),
+            self.payroll.key,
+            &[],
+            ProofLocation::ContextStateAccount(proof_context.key),
+        )?;
+        self.invoke(
+            &ix,
+            &[self.vault.clone(), self.mint.clone(), proof_context, self.payroll.clone()],
+        )
+    }
+
+    /// Confidentially transfer from the vault to `destination` using a
+    /// pre-verified transfer proof context
+    pub fn transfer(
+        &self,
+        destination: AccountInfo<'info>,
+        proof_context: AccountInfo<'info>,
+        new_source_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+    ) -> Result<()> {
+        let ix = inner_transfer(
+            self.token_program.key,
+            self.vault.key,
+            self.mint.key,
+            destination.key,
+            AeCiphertext(new_source_decryptable_balance),
+            self.payroll.key,
+            &[],
+            ProofLocation::ContextStateAccount(proof_context.key),
+        )?;
+        self.invoke(
+            &ix,
+            &[
+                self.vault.clone(),
+                self.mint.clone(),
+                destination,
+                proof_context,
+                self.payroll.clone(),
+            ],
+        )
+    }
+
+    fn invoke(&self, ix: &Instruction, accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let payroll_id = self.payroll_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[b"payroll", self.creator.as_ref(), &payroll_id, &[self.payroll_bump]]];
+
+        invoke_signed(ix, accounts, signer_seeds)?;
+        Ok(())
+    }
+}
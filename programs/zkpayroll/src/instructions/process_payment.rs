@@ -1,18 +1,39 @@
 use anchor_lang::prelude::*;
-use crate::state::{Payroll, Employee, PaymentRecord};
-use crate::errors::{PayrollError, SCREENING_VALIDITY_SECONDS};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
+use crate::state::{
+    Payroll, Employee, PaymentCommitments, PaymentOpenings, PaymentRecord, PaymentType,
+    PayrollRun, Role, RoleRegistry, RunStatus, SalaryHistory,
+};
+use crate::errors::PayrollError;
+use crate::events::{PaymentProcessed, PayrollRunCompleted};
+use crate::proof;
+use crate::shadowwire;
 
 #[derive(Accounts)]
-#[instruction(salary_amount: u64, tax_amount: u64, payment_timestamp: i64)]
+#[instruction(salary_amount: u64, tax_amount: u64, payment_type: PaymentType)]
 pub struct ProcessPayment<'info> {
     /// Payroll configuration
     #[account(
-        seeds = [b"payroll", authority.key().as_ref()],
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
         bump = payroll.bump,
-        has_one = authority @ PayrollError::Unauthorized
+        constraint = payroll.approval_policy.is_none() @ PayrollError::ApprovalRequired
     )]
     pub payroll: Account<'info, Payroll>,
 
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
     /// Employee receiving payment
     #[account(
         mut,
@@ -21,40 +42,104 @@ pub struct ProcessPayment<'info> {
     )]
     pub employee: Account<'info, Employee>,
 
+    /// Employee's salary history (commitment in force for the period)
+    #[account(
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump = salary_history.bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
     /// Payment record (created for this payment)
     #[account(
         init,
-        payer = authority,
+        payer = operator,
         space = PaymentRecord::LEN,
         seeds = [
             b"payment",
             employee.key().as_ref(),
-            &payment_timestamp.to_le_bytes()
+            &payroll.current_period.to_le_bytes(),
+            &payment_type.record_index().to_le_bytes()
         ],
         bump
     )]
     pub payment_record: Account<'info, PaymentRecord>,
 
-    /// Employer authority
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    /// Payroll run the payment counts towards (required while one is open)
+    #[account(
+        mut,
+        has_one = payroll @ PayrollError::Unauthorized
+    )]
+    pub payroll_run: Option<Account<'info, PayrollRun>>,
 
-    /// Employer's ShadowWire confidential account (source of funds)
-    /// CHECK: This is a ShadowWire confidential account
+    /// Payroll operator (payroll authority or role holder)
     #[account(mut)]
-    pub employer_confidential_account: UncheckedAccount<'info>,
+    pub operator: Signer<'info>,
+
+    /// HR admin signing off an off-cycle payment (required for off-cycle
+    /// payments, must not be the operator)
+    #[account(
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub hr_admin: Option<Signer<'info>>,
+
+    /// Mint of the token salaries are paid in
+    pub mint: Account<'info, Mint>,
+
+    /// Payroll treasury vault (source of funds)
+    #[account(
+        mut,
+        seeds = [b"treasury", payroll.key().as_ref()],
+        bump = payroll.treasury_bump,
+        token::mint = mint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// Employee's token account (receives net salary)
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = employee_token_account.owner == employee.wallet
+            @ PayrollError::InvalidTokenAccount
+    )]
+    pub employee_token_account: Account<'info, TokenAccount>,
+
+    /// Tax authority's token account (receives withheld tax)
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = tax_token_account.owner == payroll.tax_authority
+            @ PayrollError::InvalidTokenAccount
+    )]
+    pub tax_token_account: Account<'info, TokenAccount>,
 
     /// Tax authority's ShadowWire confidential account
-    /// CHECK: This is a ShadowWire confidential account
-    #[account(mut)]
+    /// CHECK: Must be the payroll's tax confidential account, a ShadowWire
+    /// confidential account of the tax authority
+    #[account(
+        mut,
+        constraint = tax_confidential_account.key() == payroll.tax_confidential_account
+            @ PayrollError::InvalidConfidentialAccount,
+        constraint = shadowwire::is_confidential_account_of(
+            &tax_confidential_account,
+            &payroll.shadowwire_program,
+            &payroll.tax_authority
+        ) @ PayrollError::InvalidConfidentialAccount
+    )]
     pub tax_confidential_account: UncheckedAccount<'info>,
 
     /// Employee's ShadowWire confidential account
-    /// CHECK: This is a ShadowWire confidential account
+    /// CHECK: Must be the employee's registered account, a ShadowWire
+    /// confidential account of the employee wallet
     #[account(
         mut,
-        constraint = employee_confidential_account.key() == employee.confidential_account 
-            @ PayrollError::InvalidConfidentialAccount
+        constraint = employee_confidential_account.key() == employee.confidential_account
+            @ PayrollError::InvalidConfidentialAccount,
+        constraint = shadowwire::is_confidential_account_of(
+            &employee_confidential_account,
+            &payroll.shadowwire_program,
+            &employee.wallet
+        ) @ PayrollError::InvalidConfidentialAccount
     )]
     pub employee_confidential_account: UncheckedAccount<'info>,
 
@@ -66,6 +151,8 @@ pub struct ProcessPayment<'info> {
     )]
     pub shadowwire_program: UncheckedAccount<'info>,
 
+    pub token_program: Program<'info, Token>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -73,69 +160,272 @@ pub fn handler(
     ctx: Context<ProcessPayment>,
     salary_amount: u64,
     tax_amount: u64,
-    payment_timestamp: i64,
+    payment_type: PaymentType,
+    commitments: PaymentCommitments,
+    openings: PaymentOpenings,
 ) -> Result<()> {
     let clock = Clock::get()?;
-    let employee = &ctx.accounts.employee;
-    let _payroll = &ctx.accounts.payroll;
-
-    // Validate amounts
-    require!(salary_amount > 0, PayrollError::InvalidAmount);
-    require!(tax_amount <= salary_amount, PayrollError::TaxExceedsSalary);
+    let payroll = &ctx.accounts.payroll;
+    let period = payroll.current_period;
 
-    // Validate timestamp is reasonably close to current time (within 5 minutes)
-    let time_diff = (clock.unix_timestamp - payment_timestamp).abs();
-    require!(time_diff < 300, PayrollError::InvalidAmount);
-
-    // Check screening is not expired (24 hours)
-    let time_since_screening = clock.unix_timestamp - employee.last_screened;
+    let net_salary = validate_amounts(payroll, salary_amount, tax_amount)?;
+    check_openings(&commitments, &openings, net_salary, tax_amount)?;
+    check_salary_commitment(&ctx.accounts.salary_history, period, payment_type, &commitments)?;
+    check_off_cycle_sign_off(
+        payment_type,
+        &ctx.accounts.operator,
+        ctx.accounts.hr_admin.as_ref(),
+    )?;
+    require!(
+        ctx.accounts.treasury.amount >= salary_amount,
+        PayrollError::InsufficientBalance
+    );
     require!(
-        time_since_screening < SCREENING_VALIDITY_SECONDS,
-        PayrollError::ScreeningExpired
+        payroll.available_for(payment_type, ctx.accounts.treasury.amount) >= salary_amount,
+        PayrollError::FundsReserved
     );
 
-    // Calculate net salary
-    let _net_salary = salary_amount.checked_sub(tax_amount).unwrap();
+    // One regular payment per period; off-cycle indexes never repeat
+    ctx.accounts.employee.record_payment(period, payment_type)?;
+    let employee = &ctx.accounts.employee;
+
+    // Check screening passes the payroll's threshold and has not expired
+    let time_since_screening = payroll.check_screening(employee, clock.unix_timestamp)?;
 
     msg!("Processing payment for employee: {}", employee.employee_id);
+    msg!("Pay period: {} ({:?})", period, payment_type);
     msg!("Screening valid (last screened {} seconds ago)", time_since_screening);
 
     // ============================================================
-    // CONFIDENTIAL TRANSFERS VIA SHADOWWIRE
+    // TOKEN TRANSFERS
     // ============================================================
-    // 
-    // In a production implementation, we would make CPI calls to ShadowWire here:
     //
-    // Step 1: Transfer tax_amount from employer -> tax_authority (confidential)
-    // Step 2: Transfer net_salary from employer -> employee (confidential)
+    // Funds move inside this instruction so a payment record can never
+    // exist without the transfers it describes:
     //
-    // The ShadowWire SDK handles:
-    // - Bulletproof generation for amount hiding
-    // - Encrypted balance updates
-    // - On-chain proof storage
-    //
-    // For this implementation, we use the ShadowWire TypeScript SDK on the frontend
-    // which signs and submits the confidential transfers in the same transaction batch.
+    // Step 1: Transfer net_salary from treasury vault -> employee
+    // Step 2: Transfer tax_amount from treasury vault -> tax_authority
     // ============================================================
 
-    // Note: Actual confidential transfers are handled by ShadowWire SDK on frontend
-    // The program records that a payment occurred but stores NO amounts
+    let treasury = TreasuryTransfer {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+        decimals: ctx.accounts.mint.decimals,
+    };
+    treasury.transfer(ctx.accounts.employee_token_account.to_account_info(), net_salary)?;
+    treasury.transfer(ctx.accounts.tax_token_account.to_account_info(), tax_amount)?;
+
+    // Count the payment against its payroll run, if any
+    let run = track_run(
+        &mut ctx.accounts.payroll,
+        ctx.accounts.payroll_run.as_mut(),
+        period,
+        payment_type,
+        1,
+        Some(salary_amount),
+        clock.unix_timestamp,
+    )?;
 
     // Create payment record (NO amounts stored for privacy!)
     let payment_record = &mut ctx.accounts.payment_record;
+    payment_record.payroll = ctx.accounts.payroll.key();
     payment_record.employee = employee.key();
-    payment_record.timestamp = payment_timestamp;
+    payment_record.timestamp = clock.unix_timestamp;
+    payment_record.period = period;
+    payment_record.payment_type = payment_type;
+    payment_record.run = run;
+    payment_record.commitments = commitments;
+    payment_record.tax_rate_bps = ctx.accounts.payroll.tax_rate_bps;
     payment_record.tax_confidential_account = ctx.accounts.tax_confidential_account.key();
     payment_record.employee_confidential_account = ctx.accounts.employee_confidential_account.key();
+    payment_record.auditors = ctx.accounts.payroll.auditors.clone();
     payment_record.verified = false;
     payment_record.verified_at = 0;
     payment_record.verifier = Pubkey::default();
     payment_record.bump = ctx.bumps.payment_record;
 
+    emit!(PaymentProcessed {
+        payroll: ctx.accounts.payroll.key(),
+        employee: employee.key(),
+        payment_record: payment_record.key(),
+        tax_confidential_account: payment_record.tax_confidential_account,
+        employee_confidential_account: payment_record.employee_confidential_account,
+        period,
+        payment_type,
+        run,
+        confidential: false,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Payment record created: {}", payment_record.key());
-    msg!("Tax sent to: {}", payment_record.tax_confidential_account);
-    msg!("Net salary sent to: {}", payment_record.employee_confidential_account);
-    msg!("IMPORTANT: Actual amounts are hidden via Bulletproof ZK proofs");
+    msg!("Tax sent to: {}", ctx.accounts.tax_token_account.key());
+    msg!("Net salary sent to: {}", ctx.accounts.employee_token_account.key());
+
+    Ok(())
+}
+
+/// Validate plaintext payment amounts against the payroll tax rate
+/// Returns the net salary
+pub(crate) fn validate_amounts(
+    payroll: &Payroll,
+    salary_amount: u64,
+    tax_amount: u64,
+) -> Result<u64> {
+    require!(salary_amount > 0, PayrollError::InvalidAmount);
+    require!(tax_amount <= salary_amount, PayrollError::TaxExceedsSalary);
 
+    // Withholding must match the configured tax rate exactly
+    require!(
+        tax_amount == payroll.required_tax(salary_amount)?,
+        PayrollError::TaxAmountMismatch
+    );
+
+    Ok(salary_amount.checked_sub(tax_amount).unwrap())
+}
+
+/// Check a regular payment against the salary HR committed to
+///
+/// The net and tax commitments must add up to the salary commitment in
+/// force for `period`. Off-cycle payments (bonuses, corrections) are exempt.
+pub(crate) fn check_salary_commitment(
+    salary_history: &SalaryHistory,
+    period: u64,
+    payment_type: PaymentType,
+    commitments: &PaymentCommitments,
+) -> Result<()> {
+    if payment_type != PaymentType::Regular {
+        return Ok(());
+    }
+
+    let salary_commitment = salary_history.commitment_for(period)?;
+    require!(
+        proof::sums_to(&commitments.net, &commitments.tax, &salary_commitment),
+        PayrollError::SalaryCommitmentMismatch
+    );
     Ok(())
 }
+
+/// Check an off-cycle payment is signed off by an HR admin other than the
+/// operator
+///
+/// Regular payments are bound to the employee's salary commitment; off-cycle
+/// payments are not, so they need a second person's approval instead.
+pub(crate) fn check_off_cycle_sign_off(
+    payment_type: PaymentType,
+    operator: &Signer,
+    hr_admin: Option<&Signer>,
+) -> Result<()> {
+    if payment_type == PaymentType::Regular {
+        return Ok(());
+    }
+
+    require!(
+        hr_admin.is_some_and(|hr_admin| hr_admin.key() != operator.key()),
+        PayrollError::OffCycleSignOffRequired
+    );
+    Ok(())
+}
+
+/// Check a plaintext payment's commitments open to the amounts it moves
+///
+/// Applies to every payment type, so the recorded commitments always hold
+/// the net salary and tax actually transferred.
+pub(crate) fn check_openings(
+    commitments: &PaymentCommitments,
+    openings: &PaymentOpenings,
+    net_salary: u64,
+    tax_amount: u64,
+) -> Result<()> {
+    require!(
+        proof::opens_to(&commitments.net, net_salary, &openings.net)
+            && proof::opens_to(&commitments.tax, tax_amount, &openings.tax),
+        PayrollError::CommitmentMismatch
+    );
+    Ok(())
+}
+
+/// Count `count` payments of `payment_type` for `period` against
+/// `payroll_run`; returns the run's address (default when paid outside a
+/// run)
+///
+/// A run is required while one is open for the payment's period.
+/// `treasury_amount` is what regular payments paid out of the treasury
+/// vault, and is released from the run's reservation; it is `None` for
+/// confidential payments, which the reservation does not cover. The whole
+/// reservation is released once the run completes.
+pub(crate) fn track_run(
+    payroll: &mut Payroll,
+    payroll_run: Option<&mut Account<PayrollRun>>,
+    period: u64,
+    payment_type: PaymentType,
+    count: u32,
+    treasury_amount: Option<u64>,
+    now: i64,
+) -> Result<Pubkey> {
+    payroll.check_run(period, payroll_run.as_ref().map(|run| run.key()))?;
+    let Some(payroll_run) = payroll_run else {
+        return Ok(Pubkey::default());
+    };
+
+    payroll_run.record_payments(period, payment_type, count, now)?;
+    if let (PaymentType::Regular, Some(amount)) = (payment_type, treasury_amount) {
+        payroll.reserved_amount = payroll.reserved_amount.saturating_sub(amount);
+    }
+    if payroll_run.status == RunStatus::Completed {
+        payroll.release_run();
+        emit!(PayrollRunCompleted {
+            payroll: payroll_run.payroll,
+            run: payroll_run.key(),
+            processed_count: payroll_run.processed_count,
+            timestamp: now,
+        });
+        msg!("Payroll run for period {} completed", payroll_run.period);
+    }
+    Ok(payroll_run.key())
+}
+
+/// Source of funds for a payment: the payroll treasury vault, which the
+/// payroll PDA signs for
+pub(crate) struct TreasuryTransfer<'info> {
+    pub token_program: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub payroll: AccountInfo<'info>,
+    pub creator: Pubkey,
+    pub payroll_id: u64,
+    pub payroll_bump: u8,
+    pub decimals: u8,
+}
+
+impl<'info> TreasuryTransfer<'info> {
+    /// Transfer `amount` from the treasury to `to` (no-op for zero amounts)
+    pub fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let payroll_id = self.payroll_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[b"payroll", self.creator.as_ref(), &payroll_id, &[self.payroll_bump]]];
+
+        token::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                TransferChecked {
+                    from: self.from.clone(),
+                    mint: self.mint.clone(),
+                    to,
+                    authority: self.payroll.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.decimals,
+        )
+    }
+}
@@ -0,0 +1,423 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{
+    Payroll, Employee, PaymentCommitments, PaymentOpenings, PaymentRecord, PaymentType,
+    PayrollRun, Role, RoleRegistry, SalaryHistory,
+};
+use crate::errors::{PayrollError, MAX_BATCH_PAYMENTS};
+use crate::events::{PaymentProcessed, PaymentSkipped, PayrollBatchProcessed};
+use crate::shadowwire;
+use crate::instructions::process_payment::{
+    check_openings, check_salary_commitment, track_run, validate_amounts, TreasuryTransfer,
+};
+
+/// Accounts per batch entry in `remaining_accounts`:
+/// (employee, salary_history, payment_record, employee_token_account)
+const ACCOUNTS_PER_ENTRY: usize = 4;
+
+/// Amounts for one employee in a batch, in `remaining_accounts` order
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct BatchPayment {
+    pub salary_amount: u64,
+    pub tax_amount: u64,
+    pub commitments: PaymentCommitments,
+    /// Blinding factors opening the commitments to the amounts
+    pub openings: PaymentOpenings,
+}
+
+#[derive(Accounts)]
+pub struct ProcessPayrollBatch<'info> {
+    /// Payroll configuration
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.approval_policy.is_none() @ PayrollError::ApprovalRequired
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Payroll run the batch counts towards (required while one is open)
+    #[account(
+        mut,
+        has_one = payroll @ PayrollError::Unauthorized
+    )]
+    pub payroll_run: Option<Account<'info, PayrollRun>>,
+
+    /// Payroll operator (payroll authority or role holder)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Mint of the token salaries are paid in
+    pub mint: Account<'info, Mint>,
+
+    /// Payroll treasury vault (source of funds)
+    #[account(
+        mut,
+        seeds = [b"treasury", payroll.key().as_ref()],
+        bump = payroll.treasury_bump,
+        token::mint = mint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// Tax authority's token account (receives the batch's withheld tax)
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = tax_token_account.owner == payroll.tax_authority
+            @ PayrollError::InvalidTokenAccount
+    )]
+    pub tax_token_account: Account<'info, TokenAccount>,
+
+    /// Tax authority's ShadowWire confidential account
+    /// CHECK: Must be the payroll's tax confidential account, a ShadowWire
+    /// confidential account of the tax authority
+    #[account(
+        constraint = tax_confidential_account.key() == payroll.tax_confidential_account
+            @ PayrollError::InvalidConfidentialAccount,
+        constraint = shadowwire::is_confidential_account_of(
+            &tax_confidential_account,
+            &payroll.shadowwire_program,
+            &payroll.tax_authority
+        ) @ PayrollError::InvalidConfidentialAccount
+    )]
+    pub tax_confidential_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// A batch entry that passed validation and still needs to be funded
+struct PreparedPayment<'info> {
+    employee_token_account: AccountInfo<'info>,
+    net_salary: u64,
+    tax_amount: u64,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessPayrollBatch<'info>>,
+    entries: Vec<BatchPayment>,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_BATCH_PAYMENTS,
+        PayrollError::InvalidBatch
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len() * ACCOUNTS_PER_ENTRY,
+        PayrollError::InvalidBatch
+    );
+
+    let clock = Clock::get()?;
+    let payroll = &ctx.accounts.payroll;
+    let period = payroll.current_period;
+
+    msg!("Processing payroll batch for period {} ({} entries)", period, entries.len());
+
+    // ============================================================
+    // VALIDATION
+    // ============================================================
+    //
+    // Every entry is checked and its payment record created before any
+    // tokens move. An entry that fails is skipped and reported through a
+    // PaymentSkipped event instead of aborting the whole batch.
+    // ============================================================
+
+    let payroll_run = ctx.accounts.payroll_run.as_ref().map(|payroll_run| payroll_run.key());
+    payroll.check_run(period, payroll_run)?;
+    let run = payroll_run.unwrap_or_default();
+    let mut prepared: Vec<PreparedPayment<'info>> = Vec::with_capacity(entries.len());
+    let mut skipped: u32 = 0;
+
+    for (entry, accounts) in entries
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(ACCOUNTS_PER_ENTRY))
+    {
+        let employee_info = &accounts[0];
+        match prepare_payment(&ctx, entry, accounts, period, run, clock.unix_timestamp) {
+            Ok(payment) => prepared.push(payment),
+            Err(err) => {
+                let error_code = match &err {
+                    Error::AnchorError(e) => e.error_code_number as u64,
+                    Error::ProgramError(e) => u64::from(e.program_error.clone()),
+                };
+                emit!(PaymentSkipped {
+                    payroll: payroll.key(),
+                    employee: employee_info.key(),
+                    period,
+                    error_code,
+                    timestamp: clock.unix_timestamp,
+                });
+                msg!("Skipped employee account {}: {}", employee_info.key(), err);
+                skipped += 1;
+            }
+        }
+    }
+
+
+    // ============================================================
+    // TOKEN TRANSFERS
+    // ============================================================
+    //
+    // Step 1: Check the treasury covers every validated payment
+    // Step 2: Transfer each net salary from treasury vault -> employee
+    // Step 3: Transfer the batch's total tax from treasury vault -> tax_authority
+    // ============================================================
+
+    let total_net = prepared
+        .iter()
+        .try_fold(0u64, |sum, p| sum.checked_add(p.net_salary))
+        .ok_or(PayrollError::InvalidAmount)?;
+    let total_tax = prepared
+        .iter()
+        .try_fold(0u64, |sum, p| sum.checked_add(p.tax_amount))
+        .ok_or(PayrollError::InvalidAmount)?;
+    require!(
+        total_net.checked_add(total_tax).ok_or(PayrollError::InvalidAmount)?
+            <= ctx.accounts.treasury.amount,
+        PayrollError::InsufficientBalance
+    );
+
+    let treasury = TreasuryTransfer {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+        decimals: ctx.accounts.mint.decimals,
+    };
+    for payment in &prepared {
+        treasury.transfer(payment.employee_token_account.clone(), payment.net_salary)?;
+    }
+    treasury.transfer(ctx.accounts.tax_token_account.to_account_info(), total_tax)?;
+
+    // Count the paid entries against the payroll run, if any
+    if !prepared.is_empty() {
+        track_run(
+            &mut ctx.accounts.payroll,
+            ctx.accounts.payroll_run.as_mut(),
+            period,
+            PaymentType::Regular,
+            prepared.len() as u32,
+            Some(total_net + total_tax),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    emit!(PayrollBatchProcessed {
+        payroll: ctx.accounts.payroll.key(),
+        period,
+        paid: prepared.len() as u32,
+        skipped,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Batch paid: {}, skipped: {}", prepared.len(), skipped);
+    msg!("Tax sent to: {}", ctx.accounts.tax_token_account.key());
+
+    Ok(())
+}
+
+/// Validate one batch entry, claim the employee's slot for the period and
+/// create its payment record
+fn prepare_payment<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, ProcessPayrollBatch<'info>>,
+    entry: &BatchPayment,
+    accounts: &'info [AccountInfo<'info>],
+    period: u64,
+    run: Pubkey,
+    now: i64,
+) -> Result<PreparedPayment<'info>> {
+    let payroll = &ctx.accounts.payroll;
+    let employee_info = &accounts[0];
+    let salary_history_info = &accounts[1];
+    let payment_record_info = &accounts[2];
+    let employee_token_info = &accounts[3];
+
+    require!(employee_info.is_writable, PayrollError::InvalidBatch);
+    let mut employee = Account::<Employee>::try_from(employee_info)?;
+    require_keys_eq!(employee.payroll, payroll.key(), PayrollError::Unauthorized);
+    require!(employee.is_active, PayrollError::EmployeeNotActive);
+
+    let salary_history = Account::<SalaryHistory>::try_from(salary_history_info)?;
+    let (salary_history_address, _) = Pubkey::find_program_address(
+        &[b"salary_history", employee_info.key().as_ref()],
+        ctx.program_id,
+    );
+    require_keys_eq!(
+        salary_history_info.key(),
+        salary_history_address,
+        PayrollError::InvalidBatch
+    );
+
+    let employee_token_account = Account::<TokenAccount>::try_from(employee_token_info)?;
+    require!(employee_token_info.is_writable, PayrollError::InvalidBatch);
+    require_keys_eq!(
+        employee_token_account.mint,
+        ctx.accounts.mint.key(),
+        PayrollError::InvalidTokenAccount
+    );
+    require_keys_eq!(
+        employee_token_account.owner,
+        employee.wallet,
+        PayrollError::InvalidTokenAccount
+    );
+
+    let net_salary = validate_amounts(payroll, entry.salary_amount, entry.tax_amount)?;
+    payroll.check_screening(&employee, now)?;
+
+    // One regular payment per period (batches never carry off-cycle payments)
+    let payment_type = PaymentType::Regular;
+    check_openings(&entry.commitments, &entry.openings, net_salary, entry.tax_amount)?;
+    check_salary_commitment(&salary_history, period, payment_type, &entry.commitments)?;
+    employee.record_payment(period, payment_type)?;
+
+    let employee_key = employee.key();
+    let period_bytes = period.to_le_bytes();
+    let index_bytes = payment_type.record_index().to_le_bytes();
+    let (record_address, record_bump) = Pubkey::find_program_address(
+        &[b"payment", employee_key.as_ref(), &period_bytes, &index_bytes],
+        ctx.program_id,
+    );
+    require_keys_eq!(
+        payment_record_info.key(),
+        record_address,
+        PayrollError::InvalidPaymentRecord
+    );
+    let signer_seeds: &[&[u8]] = &[
+        b"payment",
+        employee_key.as_ref(),
+        &period_bytes,
+        &index_bytes,
+        &[record_bump],
+    ];
+    create_payment_record_account(ctx, payment_record_info, signer_seeds)?;
+
+    // Create payment record (NO amounts stored for privacy!)
+    let payment_record = PaymentRecord {
+        payroll: payroll.key(),
+        employee: employee_key,
+        timestamp: now,
+        period,
+        payment_type,
+        run,
+        commitments: entry.commitments,
+        tax_rate_bps: payroll.tax_rate_bps,
+        tax_confidential_account: ctx.accounts.tax_confidential_account.key(),
+        employee_confidential_account: employee.confidential_account,
+        auditors: payroll.auditors.clone(),
+        verified: false,
+        verified_at: 0,
+        verifier: Pubkey::default(),
+        bump: record_bump,
+    };
+    payment_record.try_serialize(&mut &mut payment_record_info.try_borrow_mut_data()?[..])?;
+
+    // Persist the employee's updated last_paid_period
+    employee.exit(ctx.program_id)?;
+
+    emit!(PaymentProcessed {
+        payroll: payroll.key(),
+        employee: employee_key,
+        payment_record: record_address,
+        tax_confidential_account: payment_record.tax_confidential_account,
+        employee_confidential_account: payment_record.employee_confidential_account,
+        period,
+        payment_type,
+        run,
+        confidential: false,
+        timestamp: now,
+    });
+
+    Ok(PreparedPayment {
+        employee_token_account: employee_token_info.clone(),
+        net_salary,
+        tax_amount: entry.tax_amount,
+    })
+}
+
+/// Allocate a payment record PDA owned by this program, paid for by the
+/// operator (handles accounts that were pre-funded with lamports)
+fn create_payment_record_account<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, ProcessPayrollBatch<'info>>,
+    payment_record_info: &AccountInfo<'info>,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    require!(
+        payment_record_info.is_writable
+            && payment_record_info.data_is_empty()
+            && payment_record_info.owner == &system_program::ID,
+        PayrollError::InvalidPaymentRecord
+    );
+
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let operator = ctx.accounts.operator.to_account_info();
+    let rent = Rent::get()?.minimum_balance(PaymentRecord::LEN);
+    let signer = &[signer_seeds];
+
+    let current_lamports = payment_record_info.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: operator,
+                    to: payment_record_info.clone(),
+                },
+                signer,
+            ),
+            rent,
+            PaymentRecord::LEN as u64,
+            ctx.program_id,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: operator,
+                    to: payment_record_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: payment_record_info.clone(),
+            },
+            signer,
+        ),
+        PaymentRecord::LEN as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: payment_record_info.clone(),
+            },
+            signer,
+        ),
+        ctx.program_id,
+    )
+}
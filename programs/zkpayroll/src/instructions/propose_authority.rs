@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::AuthorityProposed;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Current authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let payroll = &mut ctx.accounts.payroll;
+
+    // Proposing again replaces any previously proposed authority
+    payroll.pending_authority = Some(new_authority);
+
+    emit!(AuthorityProposed {
+        payroll: payroll.key(),
+        authority: payroll.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Authority transfer proposed");
+    msg!("Current authority: {}", payroll.authority);
+    msg!("Proposed authority: {}", new_authority);
+
+    Ok(())
+}
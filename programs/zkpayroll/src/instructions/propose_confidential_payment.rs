@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    Payroll, Employee, PaymentCommitments, PaymentOpenings, PaymentProposal, PaymentType, Role,
+    RoleRegistry, SalaryHistory,
+};
+use crate::errors::PayrollError;
+use crate::events::PaymentProposed;
+use crate::instructions::process_payment::check_salary_commitment;
+
+#[derive(Accounts)]
+#[instruction(payment_type: PaymentType)]
+pub struct ProposeConfidentialPayment<'info> {
+    /// Payroll configuration
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payroll.approval_policy.is_some() @ PayrollError::NoApprovalPolicy
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Employee to be paid
+    #[account(
+        constraint = employee.payroll == payroll.key() @ PayrollError::Unauthorized,
+        constraint = employee.is_active @ PayrollError::EmployeeNotActive
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// Employee's salary history (commitment in force for the period)
+    #[account(
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump = salary_history.bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
+    /// Payment proposal (created for this payment)
+    #[account(
+        init,
+        payer = operator,
+        space = PaymentProposal::LEN,
+        seeds = [
+            b"proposal",
+            employee.key().as_ref(),
+            &payroll.current_period.to_le_bytes(),
+            &payment_type.record_index().to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, PaymentProposal>,
+
+    /// Payroll operator (payroll authority or role holder)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeConfidentialPayment>,
+    payment_type: PaymentType,
+    commitments: PaymentCommitments,
+) -> Result<()> {
+    // Amounts stay hidden: approvers sign off on the commitments, which the
+    // transfer proofs must match at execution
+    check_salary_commitment(
+        &ctx.accounts.salary_history,
+        ctx.accounts.payroll.current_period,
+        payment_type,
+        &commitments,
+    )?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.payroll = ctx.accounts.payroll.key();
+    proposal.employee = ctx.accounts.employee.key();
+    proposal.proposer = ctx.accounts.operator.key();
+    proposal.salary_amount = 0;
+    proposal.tax_amount = 0;
+    proposal.period = ctx.accounts.payroll.current_period;
+    proposal.payment_type = payment_type;
+    proposal.commitments = commitments;
+    proposal.openings = PaymentOpenings::default();
+    proposal.confidential = true;
+    proposal.approvals = Vec::new();
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(PaymentProposed {
+        payroll: proposal.payroll,
+        employee: proposal.employee,
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        period: proposal.period,
+        payment_type,
+    });
+
+    msg!("Confidential payment proposed for employee: {}", ctx.accounts.employee.employee_id);
+    msg!("Proposal: {}", proposal.key());
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, Employee, Role, RoleRegistry};
+use crate::errors::PayrollError;
+use crate::events::EmployeeReactivated;
+
+#[derive(Accounts)]
+pub struct ReactivateEmployee<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the HR admin role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Employee to reactivate
+    #[account(
+        mut,
+        constraint = employee.payroll == payroll.key() @ PayrollError::Unauthorized,
+        constraint = !employee.is_active @ PayrollError::EmployeeAlreadyActive
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// HR admin (payroll authority or role holder)
+    pub hr_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReactivateEmployee>) -> Result<()> {
+    let clock = Clock::get()?;
+    let employee = &mut ctx.accounts.employee;
+    let payroll = &mut ctx.accounts.payroll;
+
+    // Require a fresh passing screening, recorded via update_screening,
+    // before the employee can be paid again
+    let time_since_screening = payroll.check_screening(employee, clock.unix_timestamp)?;
+
+    employee.is_active = true;
+    employee.deactivated_at = 0;
+
+    // Update payroll stats
+    payroll.employee_count = payroll.employee_count.checked_add(1).unwrap();
+
+    emit!(EmployeeReactivated {
+        payroll: payroll.key(),
+        employee: employee.key(),
+        employee_id: employee.employee_id.clone(),
+        screening_score: employee.screening_score,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Employee reactivated: {} ({})", employee.name, employee.employee_id);
+    msg!("Screening score: {}/100 ({} seconds ago)", employee.screening_score, time_since_screening);
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::solana_zk_token_sdk::{
+    instruction::BatchedGroupedCiphertext2HandlesValidityProofContext,
+    zk_token_proof_instruction::ProofType,
+};
+use crate::state::{
+    AuditChunks, AuditEntry, Payroll, PaymentAudit, PaymentRecord, Role, RoleRegistry,
+};
+use crate::errors::PayrollError;
+use crate::events::PaymentAuditRecorded;
+use crate::proof::{
+    audit_handles, chunks_sum_to, load_proof_context, load_range_proof_context, proves_ranges,
+    AUDIT_CHUNK_BITS,
+};
+
+/// Validity proofs per auditor in `remaining_accounts`: (net, tax)
+const PROOFS_PER_AUDITOR: usize = 2;
+
+#[derive(Accounts)]
+pub struct RecordPaymentAudit<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the payroll operator role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &operator.key(), Role::PayrollOperator)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Payment record whose amounts are disclosed
+    #[account(
+        has_one = payroll @ PayrollError::Unauthorized,
+        constraint = !payment_record.verified @ PayrollError::AlreadyVerified,
+        constraint = !payment_record.auditors.is_empty() @ PayrollError::InvalidAuditor
+    )]
+    pub payment_record: Account<'info, PaymentRecord>,
+
+    /// Payment audit (PDA)
+    #[account(
+        init,
+        payer = operator,
+        space = PaymentAudit::LEN,
+        seeds = [b"audit", payment_record.key().as_ref()],
+        bump
+    )]
+    pub payment_audit: Box<Account<'info, PaymentAudit>>,
+
+    /// Pre-verified BatchedRangeProofU128 context over the amount chunks
+    /// CHECK: Owner, proof type and commitments are checked in the handler
+    pub range_proof_context: UncheckedAccount<'info>,
+
+    /// Payroll operator (payroll authority or role holder)
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordPaymentAudit>) -> Result<()> {
+    let clock = Clock::get()?;
+    let auditors = &ctx.accounts.payment_record.auditors;
+    let commitments = &ctx.accounts.payment_record.commitments;
+
+    // ============================================================
+    // AUDITOR DISCLOSURE
+    // ============================================================
+    //
+    // Auditors can only decrypt small amounts, so the net and tax amounts
+    // are split into 32-bit low and high chunks with C_lo + 2^32 * C_hi = C.
+    //
+    // For each auditor in the record's snapshot, in order, the client
+    // verifies two BatchedGroupedCiphertext2HandlesValidity proofs with the
+    // ZK Token Proof program (net chunks, then tax chunks) and passes their
+    // context accounts as remaining accounts. Every proof reuses the same
+    // chunk commitments, and one range proof shows each chunk fits in 32
+    // bits, so the stored decrypt handles open exactly the paid amounts.
+    // ============================================================
+
+    require!(
+        ctx.remaining_accounts.len() == auditors.len() * PROOFS_PER_AUDITOR,
+        PayrollError::AuditRequired
+    );
+
+    let mut chunks: Option<AuditChunks> = None;
+    let mut entries = Vec::with_capacity(auditors.len());
+    for (auditor, proof_contexts) in auditors
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(PROOFS_PER_AUDITOR))
+    {
+        let net_context: BatchedGroupedCiphertext2HandlesValidityProofContext =
+            load_proof_context(
+                &proof_contexts[0],
+                ProofType::BatchedGroupedCiphertext2HandlesValidity,
+            )?;
+        let tax_context: BatchedGroupedCiphertext2HandlesValidityProofContext =
+            load_proof_context(
+                &proof_contexts[1],
+                ProofType::BatchedGroupedCiphertext2HandlesValidity,
+            )?;
+
+        // The first auditor's proofs fix the chunk commitments; every other
+        // auditor's proofs must reuse them
+        let expected = *chunks.get_or_insert_with(|| AuditChunks {
+            net_lo: ciphertext_commitment(&net_context.grouped_ciphertext_lo.0),
+            net_hi: ciphertext_commitment(&net_context.grouped_ciphertext_hi.0),
+            tax_lo: ciphertext_commitment(&tax_context.grouped_ciphertext_lo.0),
+            tax_hi: ciphertext_commitment(&tax_context.grouped_ciphertext_hi.0),
+        });
+        let (net_lo_handle, net_hi_handle) =
+            audit_handles(&net_context, auditor, &expected.net_lo, &expected.net_hi)
+                .ok_or(PayrollError::ProofVerificationFailed)?;
+        let (tax_lo_handle, tax_hi_handle) =
+            audit_handles(&tax_context, auditor, &expected.tax_lo, &expected.tax_hi)
+                .ok_or(PayrollError::ProofVerificationFailed)?;
+
+        entries.push(AuditEntry {
+            auditor: *auditor,
+            net_lo_handle,
+            net_hi_handle,
+            tax_lo_handle,
+            tax_hi_handle,
+        });
+    }
+    let chunks = chunks.ok_or(PayrollError::AuditRequired)?;
+
+    // The chunks must recombine to the recorded commitments...
+    require!(
+        chunks_sum_to(&chunks.net_lo, &chunks.net_hi, &commitments.net)
+            && chunks_sum_to(&chunks.tax_lo, &chunks.tax_hi, &commitments.tax),
+        PayrollError::ProofVerificationFailed
+    );
+
+    // ...and each fit in 32 bits so auditors can decrypt them
+    let range_context = load_range_proof_context(
+        &ctx.accounts.range_proof_context,
+        ProofType::BatchedRangeProofU128,
+    )?;
+    require!(
+        proves_ranges(
+            &range_context,
+            &[
+                (chunks.net_lo, AUDIT_CHUNK_BITS),
+                (chunks.net_hi, AUDIT_CHUNK_BITS),
+                (chunks.tax_lo, AUDIT_CHUNK_BITS),
+                (chunks.tax_hi, AUDIT_CHUNK_BITS),
+            ],
+        ),
+        PayrollError::ProofVerificationFailed
+    );
+
+    let payment_audit = &mut ctx.accounts.payment_audit;
+    payment_audit.payroll = ctx.accounts.payroll.key();
+    payment_audit.payment_record = ctx.accounts.payment_record.key();
+    payment_audit.chunks = chunks;
+    payment_audit.entries = entries;
+    payment_audit.recorded_at = clock.unix_timestamp;
+    payment_audit.bump = ctx.bumps.payment_audit;
+
+    emit!(PaymentAuditRecorded {
+        payroll: payment_audit.payroll,
+        payment_record: payment_audit.payment_record,
+        payment_audit: payment_audit.key(),
+        auditor_count: payment_audit.entries.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Payment audit recorded: {}", payment_audit.key());
+    msg!("Amounts disclosed to {} auditor(s)", payment_audit.entries.len());
+
+    Ok(())
+}
+
+/// Pedersen commitment at the start of a grouped ciphertext
+fn ciphertext_commitment(ciphertext: &[u8]) -> [u8; 32] {
+    let mut commitment = [0u8; 32];
+    commitment.copy_from_slice(&ciphertext[..32]);
+    commitment
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, ScreeningProvider};
+use crate::errors::PayrollError;
+use crate::events::ScreeningProviderRegistered;
+
+#[derive(Accounts)]
+#[instruction(provider: Pubkey)]
+pub struct RegisterScreeningProvider<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Screening provider registration (PDA)
+    #[account(
+        init,
+        payer = authority,
+        space = ScreeningProvider::LEN,
+        seeds = [b"screening_provider", payroll.key().as_ref(), provider.as_ref()],
+        bump
+    )]
+    pub screening_provider: Account<'info, ScreeningProvider>,
+
+    /// Authority (must match payroll.authority)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterScreeningProvider>, provider: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let screening_provider = &mut ctx.accounts.screening_provider;
+
+    screening_provider.payroll = ctx.accounts.payroll.key();
+    screening_provider.provider = provider;
+    screening_provider.registered_at = clock.unix_timestamp;
+    screening_provider.bump = ctx.bumps.screening_provider;
+
+    emit!(ScreeningProviderRegistered {
+        payroll: screening_provider.payroll,
+        provider,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Screening provider registered: {}", provider);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::AuditorRemoved;
+
+#[derive(Accounts)]
+pub struct RemoveAuditor<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveAuditor>, auditor: [u8; 32]) -> Result<()> {
+    let payroll = &mut ctx.accounts.payroll;
+
+    let index = payroll
+        .auditors
+        .iter()
+        .position(|a| *a == auditor)
+        .ok_or(PayrollError::InvalidAuditor)?;
+    payroll.auditors.remove(index);
+
+    emit!(AuditorRemoved {
+        payroll: payroll.key(),
+        auditor,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Auditor removed ({} remaining)", payroll.auditors.len());
+
+    Ok(())
+}
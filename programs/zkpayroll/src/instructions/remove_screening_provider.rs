@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, ScreeningProvider};
+use crate::errors::PayrollError;
+use crate::events::ScreeningProviderRemoved;
+
+#[derive(Accounts)]
+pub struct RemoveScreeningProvider<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Screening provider registration to close
+    #[account(
+        mut,
+        close = authority,
+        has_one = payroll @ PayrollError::Unauthorized
+    )]
+    pub screening_provider: Account<'info, ScreeningProvider>,
+
+    /// Authority (must match payroll.authority)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveScreeningProvider>) -> Result<()> {
+    let provider = ctx.accounts.screening_provider.provider;
+
+    emit!(ScreeningProviderRemoved {
+        payroll: ctx.accounts.payroll.key(),
+        provider,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Screening provider removed: {}", provider);
+
+    Ok(())
+}
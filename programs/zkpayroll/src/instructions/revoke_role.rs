@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, Role, RoleRegistry};
+use crate::errors::PayrollError;
+use crate::events::RoleRevoked;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry to update
+    #[account(
+        mut,
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>, member: Pubkey, role: Role) -> Result<()> {
+    let role_registry = &mut ctx.accounts.role_registry;
+
+    role_registry.revoke(&member, role)?;
+
+    emit!(RoleRevoked {
+        payroll: ctx.accounts.payroll.key(),
+        member,
+        role,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Role {:?} revoked from {}", role, member);
+
+    Ok(())
+}
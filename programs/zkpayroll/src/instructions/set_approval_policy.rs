@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, ApprovalPolicy};
+use crate::errors::PayrollError;
+use crate::events::ApprovalPolicySet;
+
+#[derive(Accounts)]
+pub struct SetApprovalPolicy<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetApprovalPolicy>,
+    approvers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let payroll = &mut ctx.accounts.payroll;
+
+    // An empty approver list with a zero threshold removes the policy
+    if approvers.is_empty() && threshold == 0 {
+        payroll.approval_policy = None;
+
+        emit!(ApprovalPolicySet {
+            payroll: payroll.key(),
+            approvers: Vec::new(),
+            threshold: 0,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Payment approval policy removed");
+        return Ok(());
+    }
+
+    require!(
+        approvers.len() <= ApprovalPolicy::MAX_APPROVERS,
+        PayrollError::InvalidApprovalPolicy
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= approvers.len(),
+        PayrollError::InvalidApprovalPolicy
+    );
+    for (i, approver) in approvers.iter().enumerate() {
+        require!(
+            !approvers[..i].contains(approver),
+            PayrollError::InvalidApprovalPolicy
+        );
+    }
+
+    emit!(ApprovalPolicySet {
+        payroll: payroll.key(),
+        approvers: approvers.clone(),
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Payment approval policy set: {} of {}", threshold, approvers.len());
+
+    payroll.approval_policy = Some(ApprovalPolicy { approvers, threshold });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::Payroll;
+use crate::errors::{PayrollError, MAX_SCREENING_SCORE, MAX_SCREENING_VALIDITY_SECONDS};
+use crate::events::ScreeningPolicyUpdated;
+
+#[derive(Accounts)]
+pub struct SetScreeningPolicy<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetScreeningPolicy>,
+    screening_threshold: u8,
+    screening_validity_seconds: i64,
+) -> Result<()> {
+    require!(
+        screening_threshold <= MAX_SCREENING_SCORE,
+        PayrollError::InvalidScreeningPolicy
+    );
+    require!(
+        (1..=MAX_SCREENING_VALIDITY_SECONDS).contains(&screening_validity_seconds),
+        PayrollError::InvalidScreeningPolicy
+    );
+
+    let payroll = &mut ctx.accounts.payroll;
+
+    payroll.screening_threshold = screening_threshold;
+    payroll.screening_validity_seconds = screening_validity_seconds;
+
+    emit!(ScreeningPolicyUpdated {
+        payroll: payroll.key(),
+        screening_threshold,
+        screening_validity_seconds,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Screening threshold: {}/100", screening_threshold);
+    msg!("Screening validity: {} seconds", screening_validity_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, Employee};
+use crate::errors::PayrollError;
+use crate::events::EmployeeWalletUpdated;
+use crate::shadowwire;
+
+#[derive(Accounts)]
+pub struct UpdateEmployeeWallet<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Employee whose wallet changes
+    #[account(
+        mut,
+        constraint = employee.payroll == payroll.key() @ PayrollError::Unauthorized
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// Employee's current wallet (omitted when recovering a lost wallet)
+    #[account(
+        constraint = current_wallet.key() == employee.wallet @ PayrollError::Unauthorized
+    )]
+    pub current_wallet: Option<Signer<'info>>,
+
+    /// Payroll authority approving a recovery in place of the current wallet
+    #[account(
+        constraint = authority.key() == payroll.authority @ PayrollError::Unauthorized
+    )]
+    pub authority: Option<Signer<'info>>,
+
+    /// New employee wallet
+    pub new_wallet: Signer<'info>,
+
+    /// New wallet's ShadowWire confidential account
+    /// CHECK: Must be a ShadowWire confidential account of the new wallet
+    #[account(
+        constraint = shadowwire::is_confidential_account_of(
+            &confidential_account,
+            &payroll.shadowwire_program,
+            &new_wallet.key()
+        ) @ PayrollError::InvalidConfidentialAccount
+    )]
+    pub confidential_account: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateEmployeeWallet>) -> Result<()> {
+    // Either the current wallet or the payroll authority must approve
+    let recovery = ctx.accounts.current_wallet.is_none();
+    require!(
+        !recovery || ctx.accounts.authority.is_some(),
+        PayrollError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let employee = &mut ctx.accounts.employee;
+    let previous_wallet = employee.wallet;
+    let new_wallet = ctx.accounts.new_wallet.key();
+
+    employee.wallet = new_wallet;
+    employee.confidential_account = ctx.accounts.confidential_account.key();
+
+    // Screening attests a wallet, so a new wallet must be re-screened (via
+    // update_screening) before it can be paid
+    if new_wallet != previous_wallet {
+        employee.screening_score = 0;
+        employee.last_screened = 0;
+        employee.screening_provider = Pubkey::default();
+    }
+
+    emit!(EmployeeWalletUpdated {
+        payroll: employee.payroll,
+        employee: employee.key(),
+        previous_wallet,
+        wallet: new_wallet,
+        confidential_account: employee.confidential_account,
+        recovery,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Wallet updated for employee: {}", employee.employee_id);
+    msg!("New wallet: {}", new_wallet);
+    msg!("Confidential account: {}", employee.confidential_account);
+    if recovery {
+        msg!("Recovery approved by payroll authority");
+    }
+
+    Ok(())
+}
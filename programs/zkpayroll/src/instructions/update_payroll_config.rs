@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, PendingConfig};
+use crate::errors::{
+    PayrollError, MAX_CONFIG_DELAY_SECONDS, MAX_TAX_RATE_BPS, MIN_PERIOD_SECONDS_CEILING,
+    MIN_PERIOD_SECONDS_FLOOR,
+};
+use crate::events::PayrollConfigProposed;
+use crate::shadowwire;
+
+#[derive(Accounts)]
+pub struct UpdatePayrollConfig<'info> {
+    /// Payroll account
+    #[account(
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+
+    /// New tax authority wallet (government)
+    /// CHECK: This is just stored as a reference, no validation needed
+    pub tax_authority: UncheckedAccount<'info>,
+
+    /// New ShadowWire program for confidential transfers
+    /// CHECK: This is the ShadowWire program ID, validated by caller
+    pub shadowwire_program: UncheckedAccount<'info>,
+
+    /// New tax authority's ShadowWire confidential account (receives withheld tax)
+    /// CHECK: Must be a ShadowWire confidential account of the new tax
+    /// authority under the new ShadowWire program
+    #[account(
+        constraint = shadowwire::is_confidential_account_of(
+            &tax_confidential_account,
+            &shadowwire_program.key(),
+            &tax_authority.key()
+        ) @ PayrollError::InvalidConfidentialAccount
+    )]
+    pub tax_confidential_account: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePayrollConfig>,
+    tax_rate_bps: u16,
+    config_delay_seconds: i64,
+    min_period_seconds: i64,
+) -> Result<()> {
+    require!(
+        tax_rate_bps <= MAX_TAX_RATE_BPS,
+        PayrollError::InvalidTaxRate
+    );
+    require!(
+        (0..=MAX_CONFIG_DELAY_SECONDS).contains(&config_delay_seconds),
+        PayrollError::InvalidConfigDelay
+    );
+    require!(
+        (MIN_PERIOD_SECONDS_FLOOR..=MIN_PERIOD_SECONDS_CEILING).contains(&min_period_seconds),
+        PayrollError::InvalidPeriodLength
+    );
+
+    let clock = Clock::get()?;
+    let payroll = &mut ctx.accounts.payroll;
+
+    // The notice period in force today governs this change, so shortening
+    // the delay cannot be used to rush a new tax rate through
+    let effective_at = clock
+        .unix_timestamp
+        .checked_add(payroll.config_delay_seconds)
+        .unwrap();
+
+    // A new proposal replaces any pending one and restarts the timelock
+    payroll.pending_config = Some(PendingConfig {
+        tax_rate_bps,
+        tax_authority: ctx.accounts.tax_authority.key(),
+        tax_confidential_account: ctx.accounts.tax_confidential_account.key(),
+        shadowwire_program: ctx.accounts.shadowwire_program.key(),
+        config_delay_seconds,
+        min_period_seconds,
+        effective_at,
+    });
+
+    emit!(PayrollConfigProposed {
+        payroll: payroll.key(),
+        tax_rate_bps,
+        tax_authority: ctx.accounts.tax_authority.key(),
+        tax_confidential_account: ctx.accounts.tax_confidential_account.key(),
+        shadowwire_program: ctx.accounts.shadowwire_program.key(),
+        config_delay_seconds,
+        min_period_seconds,
+        effective_at,
+    });
+
+    msg!("Payroll config change proposed");
+    msg!("New tax rate: {}%", tax_rate_bps as f64 / 100.0);
+    msg!("New tax authority: {}", ctx.accounts.tax_authority.key());
+    msg!("Effective at: {}", effective_at);
+
+    Ok(())
+}
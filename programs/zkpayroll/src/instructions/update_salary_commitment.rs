@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{Payroll, Employee, Role, RoleRegistry, SalaryEntry, SalaryHistory};
+use crate::errors::PayrollError;
+use crate::events::SalaryCommitmentUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateSalaryCommitment<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Role registry (signer must hold the HR admin role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &hr_admin.key(), Role::HrAdmin)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// Employee whose salary changes
+    #[account(
+        mut,
+        constraint = employee.payroll == payroll.key() @ PayrollError::Unauthorized
+    )]
+    pub employee: Account<'info, Employee>,
+
+    /// Employee's salary history (new commitment is appended)
+    #[account(
+        mut,
+        seeds = [b"salary_history", employee.key().as_ref()],
+        bump = salary_history.bump
+    )]
+    pub salary_history: Account<'info, SalaryHistory>,
+
+    /// Employee wallet co-signing the change (optional)
+    #[account(
+        constraint = employee_wallet.key() == employee.wallet @ PayrollError::Unauthorized
+    )]
+    pub employee_wallet: Option<Signer<'info>>,
+
+    /// HR admin (payroll authority or role holder)
+    pub hr_admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateSalaryCommitment>,
+    salary_commitment: [u8; 32],
+    effective_period: u64,
+) -> Result<()> {
+    let current_period = ctx.accounts.payroll.current_period;
+    require!(
+        effective_period >= current_period,
+        PayrollError::InvalidEffectivePeriod
+    );
+
+    let clock = Clock::get()?;
+    let employee = &mut ctx.accounts.employee;
+    let employee_signed = ctx.accounts.employee_wallet.is_some();
+
+    employee.salary_commitment = salary_commitment;
+
+    ctx.accounts.salary_history.record(SalaryEntry {
+        commitment: salary_commitment,
+        effective_period,
+        changed_by: ctx.accounts.hr_admin.key(),
+        employee_signed,
+        recorded_at: clock.unix_timestamp,
+    });
+
+    emit!(SalaryCommitmentUpdated {
+        payroll: employee.payroll,
+        employee: employee.key(),
+        salary_commitment,
+        effective_period,
+        changed_by: ctx.accounts.hr_admin.key(),
+        employee_signed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Salary commitment updated for employee: {}", employee.employee_id);
+    msg!("Effective from pay period {}", effective_period);
+    if employee_signed {
+        msg!("Co-signed by employee: {}", employee.wallet);
+    }
+
+    Ok(())
+}
@@ -1,17 +1,35 @@
 use anchor_lang::prelude::*;
-use crate::state::{Payroll, Employee};
-use crate::errors::{PayrollError, SCREENING_THRESHOLD};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::attestation::ScreeningAttestation;
+use crate::state::{
+    Payroll, Employee, Role, RoleRegistry, ScreeningEntry, ScreeningHistory, ScreeningProvider,
+};
+use crate::errors::PayrollError;
+use crate::events::ScreeningUpdated;
 
 #[derive(Accounts)]
 pub struct UpdateScreening<'info> {
     /// Payroll account
     #[account(
-        seeds = [b"payroll", authority.key().as_ref()],
-        bump = payroll.bump,
-        has_one = authority @ PayrollError::Unauthorized
+        mut,
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump
     )]
     pub payroll: Account<'info, Payroll>,
 
+    /// Role registry (signer must hold the compliance officer role)
+    #[account(
+        seeds = [b"roles", payroll.key().as_ref()],
+        bump = role_registry.bump,
+        constraint = role_registry.authorizes(&payroll, &compliance_officer.key(), Role::ComplianceOfficer)
+            @ PayrollError::MissingRole
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
     /// Employee to update screening for
     #[account(
         mut,
@@ -19,24 +37,89 @@ pub struct UpdateScreening<'info> {
     )]
     pub employee: Account<'info, Employee>,
 
-    /// Authority (must match payroll.authority)
-    pub authority: Signer<'info>,
+    /// Employee's screening history (new result is appended)
+    #[account(
+        mut,
+        seeds = [b"screening_history", employee.key().as_ref()],
+        bump = screening_history.bump
+    )]
+    pub screening_history: Account<'info, ScreeningHistory>,
+
+    /// Screening provider that signed the attestation
+    #[account(
+        seeds = [
+            b"screening_provider",
+            payroll.key().as_ref(),
+            screening_provider.provider.as_ref()
+        ],
+        bump = screening_provider.bump
+    )]
+    pub screening_provider: Account<'info, ScreeningProvider>,
+
+    /// Instructions sysvar (holds the Ed25519 attestation signature)
+    /// CHECK: Address is checked against the sysvar ID
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Compliance officer (payroll authority or role holder)
+    pub compliance_officer: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<UpdateScreening>, new_score: u8) -> Result<()> {
+pub fn handler(ctx: Context<UpdateScreening>, new_score: u8, screened_at: i64) -> Result<()> {
     let clock = Clock::get()?;
     let employee = &mut ctx.accounts.employee;
+    let payroll = &mut ctx.accounts.payroll;
+
+    // Score must be attested by a registered screening provider
+    let attestation = ScreeningAttestation {
+        wallet: employee.wallet,
+        score: new_score,
+        screened_at,
+        provider: ctx.accounts.screening_provider.provider,
+    };
+    attestation.verify(
+        &ctx.accounts.instructions_sysvar,
+        clock.unix_timestamp,
+        payroll.screening_validity_seconds,
+    )?;
+
+    // An older attestation must not overwrite a newer result
+    require!(
+        screened_at > employee.last_screened,
+        PayrollError::StaleAttestation
+    );
 
     // Update screening info
     employee.screening_score = new_score;
-    employee.last_screened = clock.unix_timestamp;
+    employee.last_screened = screened_at;
+    employee.screening_provider = attestation.provider;
+
+    ctx.accounts.screening_history.record(ScreeningEntry {
+        score: new_score,
+        screened_at,
+        provider: attestation.provider,
+        submitted_by: ctx.accounts.compliance_officer.key(),
+        recorded_at: clock.unix_timestamp,
+    });
 
     // If score drops below threshold, deactivate employee
-    if new_score < SCREENING_THRESHOLD {
+    if new_score < payroll.screening_threshold && employee.is_active {
         employee.is_active = false;
+        employee.deactivated_at = clock.unix_timestamp;
+        payroll.employee_count = payroll.employee_count.saturating_sub(1);
         msg!("WARNING: Employee {} deactivated due to low screening score", employee.employee_id);
     }
 
+    emit!(ScreeningUpdated {
+        payroll: employee.payroll,
+        employee: employee.key(),
+        screening_score: new_score,
+        screening_provider: attestation.provider,
+        screened_at,
+        is_active: employee.is_active,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!("Screening updated for employee: {}", employee.employee_id);
     msg!("New score: {}/100", new_score);
     msg!("Screened at: {}", employee.last_screened);
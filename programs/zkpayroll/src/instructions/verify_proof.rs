@@ -1,6 +1,18 @@
 use anchor_lang::prelude::*;
-use crate::state::PaymentRecord;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::StateWithExtensions,
+    solana_zk_token_sdk::zk_token_proof_instruction::ProofType,
+    state::Account as Token2022Account,
+};
+use crate::state::{Payroll, PaymentAudit, PaymentRecord};
 use crate::errors::PayrollError;
+use crate::events::ProofVerified;
+use crate::proof::{load_range_proof_context, proves_ranges, tax_bound_commitments, TAX_BOUND_BITS};
+use crate::shadowwire;
+
+/// Bit length proven for each committed payment amount
+const PAYMENT_AMOUNT_BITS: u8 = 64;
 
 #[derive(Accounts)]
 pub struct VerifyProof<'info> {
@@ -11,10 +23,42 @@ pub struct VerifyProof<'info> {
     )]
     pub payment_record: Account<'info, PaymentRecord>,
 
-    /// Tax confidential account (contains Bulletproof)
-    /// CHECK: This is a ShadowWire confidential account with proof data
+    /// Payroll the payment record belongs to
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        constraint = payment_record.payroll == payroll.key() @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Tax confidential account recorded on the payment
+    /// CHECK: Must be the record's account; owner program and layout are checked in the handler
+    #[account(
+        constraint = tax_confidential_account.key() == payment_record.tax_confidential_account
+            @ PayrollError::InvalidConfidentialAccount
+    )]
     pub tax_confidential_account: UncheckedAccount<'info>,
 
+    /// Payment amounts disclosed to the auditors (required once any
+    /// auditor is registered)
+    #[account(
+        seeds = [b"audit", payment_record.key().as_ref()],
+        bump = payment_audit.bump
+    )]
+    pub payment_audit: Option<Account<'info, PaymentAudit>>,
+
+    /// Batched range proof context verified by the ZK Token Proof program
+    /// CHECK: Owner, proof type and commitments are checked in the handler
+    pub range_proof_context: UncheckedAccount<'info>,
+
+    /// Batched range proof context over the record's tax rounding bounds
+    /// CHECK: Owner, proof type and commitments are checked in the handler
+    pub tax_proof_context: UncheckedAccount<'info>,
+
     /// Anyone can verify proofs (public verification)
     pub verifier: Signer<'info>,
 }
@@ -27,39 +71,93 @@ pub fn handler(ctx: Context<VerifyProof>) -> Result<()> {
     // BULLETPROOF VERIFICATION
     // ============================================================
     //
-    // In a production implementation, we would:
-    // 1. Read the Bulletproof from the confidential account data
-    // 2. Parse the proof structure
-    // 3. Verify the proof is well-formed
+    // The client verifies a batched range proof (Bulletproof) over the
+    // record's net and tax commitments with the ZK Token Proof program,
+    // which stores the result in a context state account:
     //
-    // However, ShadowWire already verified the proof during the transfer.
-    // This instruction confirms the proof exists and the transaction succeeded.
+    // 1. The context account must be owned by the ZK Token Proof program
+    // 2. It must hold a BatchedRangeProofU128 context
+    // 3. It must cover exactly [net, tax] with 64 bits each, proving both
+    //    committed amounts are non-negative 64-bit values
     //
-    // The key insight is:
-    // - If the confidential transfer succeeded, the Bulletproof is valid
-    // - ShadowWire's on-chain program validates proofs at transfer time
-    // - We're just confirming the payment record matches valid transfers
+    // A second BatchedRangeProofU64 context proves the tax was withheld at
+    // the recorded rate: it must cover the two rounding bounds derived
+    // from [net, tax] (see `tax_bound_commitments`) with 32 bits each.
     // ============================================================
 
-    // Verify the tax confidential account reference matches
+    let context = load_range_proof_context(
+        &ctx.accounts.range_proof_context,
+        ProofType::BatchedRangeProofU128,
+    )?;
     require!(
-        ctx.accounts.tax_confidential_account.key() == payment_record.tax_confidential_account,
-        PayrollError::InvalidConfidentialAccount
+        proves_ranges(
+            &context,
+            &[
+                (payment_record.commitments.net, PAYMENT_AMOUNT_BITS),
+                (payment_record.commitments.tax, PAYMENT_AMOUNT_BITS),
+            ],
+        ),
+        PayrollError::ProofVerificationFailed
+    );
+
+    let tax_context = load_range_proof_context(
+        &ctx.accounts.tax_proof_context,
+        ProofType::BatchedRangeProofU64,
+    )?;
+    let [lower_bound, upper_bound] = tax_bound_commitments(
+        &payment_record.commitments.net,
+        &payment_record.commitments.tax,
+        payment_record.tax_rate_bps,
+    )?;
+    require!(
+        proves_ranges(
+            &tax_context,
+            &[(lower_bound, TAX_BOUND_BITS), (upper_bound, TAX_BOUND_BITS)],
+        ),
+        PayrollError::TaxProofFailed
     );
 
-    // Check that the confidential account exists and has data
-    // This confirms the ShadowWire transfer occurred
+    // The tax account must still be a ShadowWire confidential account, or a
+    // Token-2022 account for records created by confidential payments
+    // (its owner wallet was checked when the payment was made)
     let account_info = &ctx.accounts.tax_confidential_account;
+    let shadowwire_program = &ctx.accounts.payroll.shadowwire_program;
     require!(
-        !account_info.data_is_empty(),
+        shadowwire::confidential_account_owner(account_info, shadowwire_program).is_some()
+            || is_token_2022_account(account_info),
         PayrollError::InvalidConfidentialAccount
     );
 
+    // Auditors registered at payment time must be able to decrypt the
+    // amounts before the record counts as verified
+    let auditors = &payment_record.auditors;
+    if !auditors.is_empty() {
+        let audit = ctx
+            .accounts
+            .payment_audit
+            .as_ref()
+            .ok_or(PayrollError::AuditRequired)?;
+        require!(
+            auditors
+                .iter()
+                .all(|auditor| audit.entries.iter().any(|entry| entry.auditor == *auditor)),
+            PayrollError::AuditRequired
+        );
+    }
+
     // Mark as verified
     payment_record.verified = true;
     payment_record.verified_at = clock.unix_timestamp;
     payment_record.verifier = ctx.accounts.verifier.key();
 
+    emit!(ProofVerified {
+        payment_record: payment_record.key(),
+        range_proof_context: ctx.accounts.range_proof_context.key(),
+        tax_proof_context: ctx.accounts.tax_proof_context.key(),
+        verifier: payment_record.verifier,
+        verified_at: payment_record.verified_at,
+    });
+
     msg!("Payment proof verified!");
     msg!("Payment record: {}", payment_record.key());
     msg!("Verified by: {}", payment_record.verifier);
@@ -67,3 +165,14 @@ pub fn handler(ctx: Context<VerifyProof>) -> Result<()> {
 
     Ok(())
 }
+
+/// Whether `info` is an initialized Token-2022 token account
+fn is_token_2022_account(info: &AccountInfo) -> bool {
+    if info.owner != &spl_token_2022::ID {
+        return false;
+    }
+    let Ok(data) = info.try_borrow_data() else {
+        return false;
+    };
+    StateWithExtensions::<Token2022Account>::unpack(&data).is_ok()
+}
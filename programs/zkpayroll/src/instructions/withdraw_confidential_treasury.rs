@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TransferChecked};
+use crate::state::Payroll;
+use crate::errors::{PayrollError, AE_CIPHERTEXT_LEN};
+use crate::events::ConfidentialTreasuryWithdrawn;
+use crate::instructions::process_confidential_payment::ConfidentialTreasury;
+
+#[derive(Accounts)]
+pub struct WithdrawConfidentialTreasury<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized,
+        constraint = payroll.active_run.is_none() @ PayrollError::FundsReserved
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Payroll confidential treasury vault (source of funds)
+    #[account(
+        mut,
+        seeds = [b"confidential_treasury", payroll.key().as_ref()],
+        bump = payroll.confidential_treasury_bump,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub confidential_treasury: InterfaceAccount<'info, TokenAccount>,
+
+    /// Mint held by the confidential treasury vault
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Token account receiving the withdrawal
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// Pre-verified withdraw proof context for the vault's available balance
+    /// CHECK: Owner, proof type and ciphertext are validated by Token-2022
+    pub withdraw_proof_context: UncheckedAccount<'info>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawConfidentialTreasury>,
+    amount: u64,
+    new_decryptable_available_balance: [u8; AE_CIPHERTEXT_LEN],
+) -> Result<()> {
+    require!(amount > 0, PayrollError::InvalidAmount);
+    // The vault backs the active run's confidential payments, but its
+    // balance is encrypted, so no amount can be reserved: nothing leaves the
+    // vault until the run completes or is cancelled
+
+    // Step 1: Move the amount from the vault's confidential balance to its
+    // public balance
+    let treasury = ConfidentialTreasury {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        vault: ctx.accounts.confidential_treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+    };
+    treasury.withdraw(
+        ctx.accounts.withdraw_proof_context.to_account_info(),
+        amount,
+        ctx.accounts.mint.decimals,
+        new_decryptable_available_balance,
+    )?;
+
+    // Step 2: Transfer the public amount out of the vault
+    let payroll = &ctx.accounts.payroll;
+    let payroll_id = payroll.payroll_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[b"payroll", payroll.creator.as_ref(), &payroll_id, &[payroll.bump]]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.confidential_treasury.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.payroll.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(ConfidentialTreasuryWithdrawn {
+        payroll: ctx.accounts.payroll.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Withdrew {} from confidential treasury to {}", amount, ctx.accounts.destination.key());
+
+    Ok(())
+}
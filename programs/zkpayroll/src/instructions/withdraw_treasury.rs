@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::Payroll;
+use crate::errors::PayrollError;
+use crate::events::TreasuryWithdrawn;
+use crate::instructions::process_payment::TreasuryTransfer;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// Payroll account
+    #[account(
+        seeds = [
+            b"payroll",
+            payroll.creator.as_ref(),
+            payroll.payroll_id.to_le_bytes().as_ref()
+        ],
+        bump = payroll.bump,
+        has_one = authority @ PayrollError::Unauthorized
+    )]
+    pub payroll: Account<'info, Payroll>,
+
+    /// Payroll treasury vault (source of funds)
+    #[account(
+        mut,
+        seeds = [b"treasury", payroll.key().as_ref()],
+        bump = payroll.treasury_bump,
+        token::mint = mint
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// Mint of the token salaries are paid in
+    pub mint: Account<'info, Mint>,
+
+    /// Token account receiving the withdrawal
+    #[account(
+        mut,
+        token::mint = mint
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// Authority (must match payroll.authority)
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, PayrollError::InvalidAmount);
+    require!(
+        ctx.accounts.treasury.amount >= amount,
+        PayrollError::InsufficientBalance
+    );
+    require!(
+        ctx.accounts.treasury.amount - amount >= ctx.accounts.payroll.reserved_amount,
+        PayrollError::FundsReserved
+    );
+
+    let treasury = TreasuryTransfer {
+        token_program: ctx.accounts.token_program.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.treasury.to_account_info(),
+        payroll: ctx.accounts.payroll.to_account_info(),
+        creator: ctx.accounts.payroll.creator,
+        payroll_id: ctx.accounts.payroll.payroll_id,
+        payroll_bump: ctx.accounts.payroll.bump,
+        decimals: ctx.accounts.mint.decimals,
+    };
+    treasury.transfer(ctx.accounts.destination.to_account_info(), amount)?;
+
+    emit!(TreasuryWithdrawn {
+        payroll: ctx.accounts.payroll.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Withdrew {} from treasury {}", amount, ctx.accounts.treasury.key());
+    msg!("Sent to: {}", ctx.accounts.destination.key());
+
+    Ok(())
+}
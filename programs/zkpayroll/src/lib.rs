@@ -1,10 +1,16 @@
 use anchor_lang::prelude::*;
 
+pub mod attestation;
 pub mod errors;
+pub mod events;
 pub mod instructions;
+pub mod proof;
+pub mod shadowwire;
 pub mod state;
 
+use errors::AE_CIPHERTEXT_LEN;
 use instructions::*;
+use state::{PaymentCommitments, PaymentOpenings, PaymentType, Role};
 
 declare_id!("APj56TZKsc3mDNBpSik46AtGzQnNKhXG6aTrtciXtRj6");
 
@@ -15,34 +21,179 @@ pub mod zk_payroll {
     /// Initialize the payroll system with tax configuration
     pub fn initialize(
         ctx: Context<Initialize>,
+        payroll_id: u64,
         tax_rate_bps: u16,
+        config_delay_seconds: i64,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, tax_rate_bps)
+        instructions::initialize::handler(ctx, payroll_id, tax_rate_bps, config_delay_seconds)
     }
 
-    /// Add a new employee with wallet screening validation
+    /// Create the payroll's treasury vault that payments are made from
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::initialize_treasury::handler(ctx)
+    }
+
+    /// Deposit tokens into the payroll treasury vault
+    pub fn deposit_treasury(ctx: Context<DepositTreasury>, amount: u64) -> Result<()> {
+        instructions::deposit_treasury::handler(ctx, amount)
+    }
+
+    /// Withdraw tokens from the payroll treasury vault (authority only)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, amount)
+    }
+
+    /// Create the payroll's Token-2022 confidential treasury vault that
+    /// confidential payments are made from
+    pub fn initialize_confidential_treasury(
+        ctx: Context<InitializeConfidentialTreasury>,
+        decryptable_zero_balance: [u8; AE_CIPHERTEXT_LEN],
+        maximum_pending_balance_credit_counter: u64,
+    ) -> Result<()> {
+        instructions::initialize_confidential_treasury::handler(
+            ctx,
+            decryptable_zero_balance,
+            maximum_pending_balance_credit_counter,
+        )
+    }
+
+    /// Move the confidential treasury's public balance into its confidential
+    /// balance and apply the pending balance (amount 0 only applies)
+    pub fn deposit_confidential_treasury(
+        ctx: Context<DepositConfidentialTreasury>,
+        amount: u64,
+        expected_pending_balance_credit_counter: u64,
+        new_decryptable_available_balance: [u8; AE_CIPHERTEXT_LEN],
+    ) -> Result<()> {
+        instructions::deposit_confidential_treasury::handler(
+            ctx,
+            amount,
+            expected_pending_balance_credit_counter,
+            new_decryptable_available_balance,
+        )
+    }
+
+    /// Withdraw tokens from the confidential treasury vault (authority only)
+    pub fn withdraw_confidential_treasury(
+        ctx: Context<WithdrawConfidentialTreasury>,
+        amount: u64,
+        new_decryptable_available_balance: [u8; AE_CIPHERTEXT_LEN],
+    ) -> Result<()> {
+        instructions::withdraw_confidential_treasury::handler(
+            ctx,
+            amount,
+            new_decryptable_available_balance,
+        )
+    }
+
+    /// Add a new employee with wallet screening validation (authority and
+    /// employee wallet sign)
     pub fn add_employee(
         ctx: Context<AddEmployee>,
         employee_id: String,
         name: String,
         salary_commitment: [u8; 32],
         screening_score: u8,
+        screened_at: i64,
+    ) -> Result<()> {
+        instructions::add_employee::handler(
+            ctx,
+            employee_id,
+            name,
+            salary_commitment,
+            screening_score,
+            screened_at,
+        )
+    }
+
+    /// Invite an employee to join the payroll
+    /// The employee account is only created once the employee accepts
+    pub fn create_employee_invite(
+        ctx: Context<CreateEmployeeInvite>,
+        employee_id: String,
+        name: String,
+        wallet: Pubkey,
+        salary_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::create_employee_invite::handler(
+            ctx,
+            employee_id,
+            name,
+            wallet,
+            salary_commitment,
+        )
+    }
+
+    /// Cancel an employee invite that has not been accepted yet
+    pub fn cancel_employee_invite(ctx: Context<CancelEmployeeInvite>) -> Result<()> {
+        instructions::cancel_employee_invite::handler(ctx)
+    }
+
+    /// Accept an employee invite, signed by the employee's wallet
+    pub fn accept_employee_invite(
+        ctx: Context<AcceptEmployeeInvite>,
+        screening_score: u8,
+        screened_at: i64,
     ) -> Result<()> {
-        instructions::add_employee::handler(ctx, employee_id, name, salary_commitment, screening_score)
+        instructions::accept_employee_invite::handler(ctx, screening_score, screened_at)
     }
 
-    /// Process salary payment with confidential transfers
-    /// Tax payment and net salary are sent via ShadowWire confidential transfers
+    /// Process salary payment
+    /// Net salary and withheld tax are transferred from the treasury vault
+    /// Off-cycle payments need an HR admin's co-signature
     pub fn process_payment(
         ctx: Context<ProcessPayment>,
         salary_amount: u64,
         tax_amount: u64,
-        payment_timestamp: i64,
+        payment_type: PaymentType,
+        commitments: PaymentCommitments,
+        openings: PaymentOpenings,
+    ) -> Result<()> {
+        instructions::process_payment::handler(
+            ctx,
+            salary_amount,
+            tax_amount,
+            payment_type,
+            commitments,
+            openings,
+        )
+    }
+
+    /// Pay many employees for the current period in one instruction
+    /// Entries that fail validation are skipped and reported via events
+    pub fn process_payroll_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessPayrollBatch<'info>>,
+        entries: Vec<BatchPayment>,
+    ) -> Result<()> {
+        instructions::process_payroll_batch::handler(ctx, entries)
+    }
+
+    /// Process salary payment with Token-2022 confidential transfers
+    /// Amounts stay encrypted; proofs are supplied as context state accounts
+    /// Off-cycle payments need an HR admin's co-signature
+    /// Under an approval policy, use propose/execute_confidential_payment
+    pub fn process_confidential_payment(
+        ctx: Context<ProcessConfidentialPayment>,
+        payment_type: PaymentType,
+        commitments: PaymentCommitments,
+        salary_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+        tax_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
     ) -> Result<()> {
-        instructions::process_payment::handler(ctx, salary_amount, tax_amount, payment_timestamp)
+        instructions::process_confidential_payment::handler(
+            ctx,
+            payment_type,
+            commitments,
+            salary_new_decryptable_balance,
+            tax_new_decryptable_balance,
+        )
     }
 
-    /// Verify the Bulletproof on a payment record
+    /// Disclose a payment's amounts to the payroll's registered auditors
+    pub fn record_payment_audit(ctx: Context<RecordPaymentAudit>) -> Result<()> {
+        instructions::record_payment_audit::handler(ctx)
+    }
+
+    /// Verify the range proof over a payment record's net and tax commitments
     pub fn verify_proof(ctx: Context<VerifyProof>) -> Result<()> {
         instructions::verify_proof::handler(ctx)
     }
@@ -51,12 +202,215 @@ pub mod zk_payroll {
     pub fn update_screening(
         ctx: Context<UpdateScreening>,
         new_score: u8,
+        screened_at: i64,
     ) -> Result<()> {
-        instructions::update_screening::handler(ctx, new_score)
+        instructions::update_screening::handler(ctx, new_score, screened_at)
     }
 
     /// Deactivate an employee
     pub fn deactivate_employee(ctx: Context<DeactivateEmployee>) -> Result<()> {
         instructions::deactivate_employee::handler(ctx)
     }
+
+    /// Set the payroll's screening threshold and validity window
+    pub fn set_screening_policy(
+        ctx: Context<SetScreeningPolicy>,
+        screening_threshold: u8,
+        screening_validity_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_screening_policy::handler(
+            ctx,
+            screening_threshold,
+            screening_validity_seconds,
+        )
+    }
+
+    /// Register a screening provider key allowed to sign attestations
+    pub fn register_screening_provider(
+        ctx: Context<RegisterScreeningProvider>,
+        provider: Pubkey,
+    ) -> Result<()> {
+        instructions::register_screening_provider::handler(ctx, provider)
+    }
+
+    /// Remove a registered screening provider key
+    pub fn remove_screening_provider(ctx: Context<RemoveScreeningProvider>) -> Result<()> {
+        instructions::remove_screening_provider::handler(ctx)
+    }
+
+    /// Register an auditor ElGamal key that payment amounts are disclosed to
+    pub fn add_auditor(ctx: Context<AddAuditor>, auditor: [u8; 32]) -> Result<()> {
+        instructions::add_auditor::handler(ctx, auditor)
+    }
+
+    /// Remove a registered auditor key
+    pub fn remove_auditor(ctx: Context<RemoveAuditor>, auditor: [u8; 32]) -> Result<()> {
+        instructions::remove_auditor::handler(ctx, auditor)
+    }
+
+    /// Reactivate an employee after a fresh passing screening
+    pub fn reactivate_employee(ctx: Context<ReactivateEmployee>) -> Result<()> {
+        instructions::reactivate_employee::handler(ctx)
+    }
+
+    /// Change an employee's salary commitment from `effective_period` on
+    /// The employee may co-sign to acknowledge the change
+    pub fn update_salary_commitment(
+        ctx: Context<UpdateSalaryCommitment>,
+        salary_commitment: [u8; 32],
+        effective_period: u64,
+    ) -> Result<()> {
+        instructions::update_salary_commitment::handler(ctx, salary_commitment, effective_period)
+    }
+
+    /// Rotate an employee's wallet and confidential account
+    /// Signed by the new wallet and the current wallet (or the payroll
+    /// authority when the current wallet is lost)
+    pub fn update_employee_wallet(ctx: Context<UpdateEmployeeWallet>) -> Result<()> {
+        instructions::update_employee_wallet::handler(ctx)
+    }
+
+    /// Close a deactivated employee account after the retention period
+    pub fn close_employee(ctx: Context<CloseEmployee>) -> Result<()> {
+        instructions::close_employee::handler(ctx)
+    }
+
+    /// Close a verified payment record after the retention period
+    pub fn close_payment_record(ctx: Context<ClosePaymentRecord>) -> Result<()> {
+        instructions::close_payment_record::handler(ctx)
+    }
+
+    /// Close the current pay period and start the next one
+    pub fn advance_pay_period(ctx: Context<AdvancePayPeriod>) -> Result<()> {
+        instructions::advance_pay_period::handler(ctx)
+    }
+
+    /// Open a payroll run tracking the current period's payments
+    pub fn open_payroll_run(ctx: Context<OpenPayrollRun>, expected_count: u32) -> Result<()> {
+        instructions::open_payroll_run::handler(ctx, expected_count)
+    }
+
+    /// Confirm the treasury covers a payroll run
+    pub fn fund_payroll_run(ctx: Context<FundPayrollRun>, funded_amount: u64) -> Result<()> {
+        instructions::fund_payroll_run::handler(ctx, funded_amount)
+    }
+
+    /// Cancel an unfinished payroll run
+    pub fn cancel_payroll_run(ctx: Context<CancelPayrollRun>) -> Result<()> {
+        instructions::cancel_payroll_run::handler(ctx)
+    }
+
+    /// Propose a payroll config change (applied after the timelock)
+    pub fn update_payroll_config(
+        ctx: Context<UpdatePayrollConfig>,
+        tax_rate_bps: u16,
+        config_delay_seconds: i64,
+        min_period_seconds: i64,
+    ) -> Result<()> {
+        instructions::update_payroll_config::handler(
+            ctx,
+            tax_rate_bps,
+            config_delay_seconds,
+            min_period_seconds,
+        )
+    }
+
+    /// Apply a pending payroll config change once its timelock has expired
+    pub fn apply_payroll_config(ctx: Context<ApplyPayrollConfig>) -> Result<()> {
+        instructions::apply_payroll_config::handler(ctx)
+    }
+
+    /// Cancel a pending payroll config change
+    pub fn cancel_payroll_config(ctx: Context<CancelPayrollConfig>) -> Result<()> {
+        instructions::cancel_payroll_config::handler(ctx)
+    }
+
+    /// Propose a new payroll authority (step 1 of 2)
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+
+    /// Accept a proposed payroll authority transfer (step 2 of 2)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+
+    /// Grant a payroll role to a member
+    pub fn grant_role(ctx: Context<GrantRole>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::grant_role::handler(ctx, member, role)
+    }
+
+    /// Revoke a payroll role from a member
+    pub fn revoke_role(ctx: Context<RevokeRole>, member: Pubkey, role: Role) -> Result<()> {
+        instructions::revoke_role::handler(ctx, member, role)
+    }
+
+    /// Configure M-of-N approval for payments (empty list and zero threshold disables it)
+    pub fn set_approval_policy(
+        ctx: Context<SetApprovalPolicy>,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_approval_policy::handler(ctx, approvers, threshold)
+    }
+
+    /// Propose a payment that needs M-of-N approval
+    pub fn propose_payment(
+        ctx: Context<ProposePayment>,
+        salary_amount: u64,
+        tax_amount: u64,
+        payment_type: PaymentType,
+        commitments: PaymentCommitments,
+        openings: PaymentOpenings,
+    ) -> Result<()> {
+        instructions::propose_payment::handler(
+            ctx,
+            salary_amount,
+            tax_amount,
+            payment_type,
+            commitments,
+            openings,
+        )
+    }
+
+    /// Approve a proposed payment
+    pub fn approve_payment(ctx: Context<ApprovePayment>) -> Result<()> {
+        instructions::approve_payment::handler(ctx)
+    }
+
+    /// Execute a payment once it has enough approvals
+    pub fn execute_payment(ctx: Context<ExecutePayment>) -> Result<()> {
+        instructions::execute_payment::handler(ctx)
+    }
+
+    /// Propose a confidential payment that needs M-of-N approval
+    /// Approvers sign off on the commitments; amounts stay encrypted
+    pub fn propose_confidential_payment(
+        ctx: Context<ProposeConfidentialPayment>,
+        payment_type: PaymentType,
+        commitments: PaymentCommitments,
+    ) -> Result<()> {
+        instructions::propose_confidential_payment::handler(ctx, payment_type, commitments)
+    }
+
+    /// Execute a confidential payment once it has enough approvals
+    pub fn execute_confidential_payment(
+        ctx: Context<ExecuteConfidentialPayment>,
+        salary_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+        tax_new_decryptable_balance: [u8; AE_CIPHERTEXT_LEN],
+    ) -> Result<()> {
+        instructions::execute_confidential_payment::handler(
+            ctx,
+            salary_new_decryptable_balance,
+            tax_new_decryptable_balance,
+        )
+    }
+
+    /// Cancel a payment proposal (proposer or authority)
+    pub fn cancel_payment(ctx: Context<CancelPayment>) -> Result<()> {
+        instructions::cancel_payment::handler(ctx)
+    }
 }
@@ -0,0 +1,322 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::solana_zk_token_sdk::{
+    curve25519::{
+        ristretto::{
+            add_ristretto, multiply_ristretto, multiscalar_multiply_ristretto,
+            subtract_ristretto, PodRistrettoPoint,
+        },
+        scalar::PodScalar,
+    },
+    instruction::{
+        BatchedGroupedCiphertext2HandlesValidityProofContext, BatchedRangeProofContext, Pod,
+        TransferProofContext,
+    },
+    zk_token_proof_instruction::ProofType,
+    zk_token_proof_program,
+    zk_token_proof_state::ProofContextState,
+};
+use crate::errors::PayrollError;
+
+/// Length of a compressed Pedersen commitment
+pub const COMMITMENT_LEN: usize = 32;
+
+/// Pedersen value generator `G` (the Ristretto basepoint, compressed)
+pub const PEDERSEN_G: [u8; COMMITMENT_LEN] = [
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+];
+
+/// Pedersen blinding generator `H`
+/// (`RistrettoPoint::hash_from_bytes::<Sha3_512>(G)`, compressed)
+pub const PEDERSEN_H: [u8; COMMITMENT_LEN] = [
+    0x8c, 0x92, 0x40, 0xb4, 0x56, 0xa9, 0xe6, 0xdc, 0x65, 0xc3, 0x77, 0xa1, 0x04, 0x8d, 0x74, 0x5f,
+    0x94, 0xa0, 0x8c, 0xdb, 0x7f, 0x44, 0xcb, 0xcd, 0x7b, 0x46, 0xf3, 0x40, 0x48, 0x87, 0x11, 0x34,
+];
+
+/// Bit length proven for each tax rounding bound
+pub const TAX_BOUND_BITS: u8 = 32;
+
+/// Bit length of each chunk an amount is split into for auditors
+pub const AUDIT_CHUNK_BITS: u8 = 32;
+
+/// Bits of a confidential transfer amount carried by its low ciphertext
+pub const TRANSFER_AMOUNT_LO_BITS: u32 = 16;
+
+/// Width of the half-up rounding window, in units of `salary * tax_rate_bps`
+const ROUNDING_WINDOW: u64 = 10_000;
+
+/// Load a batched range proof context that the ZK Token Proof program
+/// verified into `info`
+pub fn load_range_proof_context(
+    info: &AccountInfo,
+    proof_type: ProofType,
+) -> Result<BatchedRangeProofContext> {
+    load_proof_context(info, proof_type)
+}
+
+/// Load a proof context of type `T` that the ZK Token Proof program
+/// verified into `info`
+///
+/// The proof program only writes a context state account after checking
+/// the proof, so reading it back is equivalent to verifying the proof.
+pub fn load_proof_context<T: Pod>(info: &AccountInfo, proof_type: ProofType) -> Result<T> {
+    require_keys_eq!(
+        *info.owner,
+        zk_token_proof_program::id(),
+        PayrollError::ProofVerificationFailed
+    );
+
+    let data = info.try_borrow_data()?;
+    let state = ProofContextState::<T>::try_from_bytes(&data)
+        .map_err(|_| error!(PayrollError::ProofVerificationFailed))?;
+    require!(
+        state.proof_type == proof_type.into(),
+        PayrollError::ProofVerificationFailed
+    );
+
+    Ok(state.proof_context)
+}
+
+/// Whether `context` covers exactly `ranges` (commitment, bit length), in
+/// order, with no further commitments
+pub fn proves_ranges(
+    context: &BatchedRangeProofContext,
+    ranges: &[([u8; COMMITMENT_LEN], u8)],
+) -> bool {
+    if ranges.len() > context.commitments.len() {
+        return false;
+    }
+
+    let covered = ranges.iter().enumerate().all(|(i, (commitment, bit_length))| {
+        context.commitments[i].0 == *commitment && context.bit_lengths[i] == *bit_length
+    });
+    let rest_empty = context.bit_lengths[ranges.len()..].iter().all(|b| *b == 0);
+
+    covered && rest_empty
+}
+
+/// Decrypt handles for `auditor` over the `lo` and `hi` chunk commitments
+///
+/// `context` must be a grouped ciphertext validity proof whose destination
+/// is `auditor` and whose ciphertexts reuse the chunk commitments, so the
+/// handles open exactly the committed chunks to the auditor's key.
+pub fn audit_handles(
+    context: &BatchedGroupedCiphertext2HandlesValidityProofContext,
+    auditor: &[u8; COMMITMENT_LEN],
+    lo: &[u8; COMMITMENT_LEN],
+    hi: &[u8; COMMITMENT_LEN],
+) -> Option<([u8; COMMITMENT_LEN], [u8; COMMITMENT_LEN])> {
+    if context.destination_pubkey.0 != *auditor {
+        return None;
+    }
+
+    // Grouped ciphertext layout: commitment || destination handle || auditor handle
+    let split = |ciphertext: &[u8]| -> ([u8; COMMITMENT_LEN], [u8; COMMITMENT_LEN]) {
+        let mut commitment = [0u8; COMMITMENT_LEN];
+        let mut handle = [0u8; COMMITMENT_LEN];
+        commitment.copy_from_slice(&ciphertext[..COMMITMENT_LEN]);
+        handle.copy_from_slice(&ciphertext[COMMITMENT_LEN..2 * COMMITMENT_LEN]);
+        (commitment, handle)
+    };
+    let (lo_commitment, lo_handle) = split(&context.grouped_ciphertext_lo.0);
+    let (hi_commitment, hi_handle) = split(&context.grouped_ciphertext_hi.0);
+
+    (lo_commitment == *lo && hi_commitment == *hi).then_some((lo_handle, hi_handle))
+}
+
+/// Whether `lo + 2^32 * hi == total` for Pedersen commitments, i.e. the
+/// chunks recombine to the committed amount
+pub fn chunks_sum_to(
+    lo: &[u8; COMMITMENT_LEN],
+    hi: &[u8; COMMITMENT_LEN],
+    total: &[u8; COMMITMENT_LEN],
+) -> bool {
+    multiply_ristretto(&scalar(1 << AUDIT_CHUNK_BITS), &PodRistrettoPoint(*hi))
+        .and_then(|shifted| add_ristretto(&PodRistrettoPoint(*lo), &shifted))
+        .is_some_and(|point| point.0 == *total)
+}
+
+/// Whether the amount a verified confidential transfer moves is committed
+/// to by `commitment`
+///
+/// Token-2022 splits the amount into a 16-bit low and a 48-bit high part,
+/// each encrypted as a grouped ciphertext that starts with its Pedersen
+/// commitment, so the whole amount is committed to by `C_lo + 2^16 * C_hi`.
+pub fn transfer_commits_to(
+    context: &TransferProofContext,
+    commitment: &[u8; COMMITMENT_LEN],
+) -> bool {
+    let ciphertext_commitment = |ciphertext: &[u8]| {
+        let mut point = [0u8; COMMITMENT_LEN];
+        point.copy_from_slice(&ciphertext[..COMMITMENT_LEN]);
+        PodRistrettoPoint(point)
+    };
+    let lo = ciphertext_commitment(&context.ciphertext_lo.0 .0);
+    let hi = ciphertext_commitment(&context.ciphertext_hi.0 .0);
+
+    multiply_ristretto(&scalar(1 << TRANSFER_AMOUNT_LO_BITS), &hi)
+        .and_then(|shifted| add_ristretto(&lo, &shifted))
+        .is_some_and(|point| point.0 == *commitment)
+}
+
+/// Commitments whose non-negativity proves that the tax commitment holds
+/// `tax_rate_bps` of the salary, rounded half up (see `Payroll::required_tax`)
+///
+/// With `salary = net + tax`, half-up rounding means
+/// `-5000 <= salary * rate - 10000 * tax < 5000`. Setting
+/// `E1 = 10000 * tax - rate * (net + tax) + 4999`, that is exactly
+/// `0 <= E1 <= 9999`, i.e. `E1 >= 0` and `E2 = 9999 - E1 >= 0`.
+///
+/// Both are computed homomorphically from the commitments, so the prover
+/// knows their openings and can range-prove them without revealing any
+/// amount. Returns `[C(E1), C(E2)]`.
+pub fn tax_bound_commitments(
+    net_commitment: &[u8; COMMITMENT_LEN],
+    tax_commitment: &[u8; COMMITMENT_LEN],
+    tax_rate_bps: u16,
+) -> Result<[[u8; COMMITMENT_LEN]; 2]> {
+    let net = PodRistrettoPoint(*net_commitment);
+    let tax = PodRistrettoPoint(*tax_commitment);
+    let g = PodRistrettoPoint(PEDERSEN_G);
+    let half_window = ROUNDING_WINDOW / 2 - 1;
+
+    let bounds = (|| {
+        let salary = add_ristretto(&net, &tax)?;
+        let scaled_tax = multiply_ristretto(&scalar(ROUNDING_WINDOW), &tax)?;
+        let scaled_salary = multiply_ristretto(&scalar(tax_rate_bps as u64), &salary)?;
+        let difference = subtract_ristretto(&scaled_tax, &scaled_salary)?;
+        let lower = add_ristretto(&difference, &multiply_ristretto(&scalar(half_window), &g)?)?;
+        let window = multiply_ristretto(&scalar(ROUNDING_WINDOW - 1), &g)?;
+        let upper = subtract_ristretto(&window, &lower)?;
+        Some([lower.0, upper.0])
+    })();
+
+    bounds.ok_or_else(|| error!(PayrollError::TaxProofFailed))
+}
+
+/// Whether `commitment` opens to `amount` with blinding factor `opening`,
+/// i.e. `commitment == amount * G + opening * H`
+pub fn opens_to(
+    commitment: &[u8; COMMITMENT_LEN],
+    amount: u64,
+    opening: &[u8; 32],
+) -> bool {
+    multiscalar_multiply_ristretto(
+        &[scalar(amount), PodScalar(*opening)],
+        &[PodRistrettoPoint(PEDERSEN_G), PodRistrettoPoint(PEDERSEN_H)],
+    )
+    .is_some_and(|point| point.0 == *commitment)
+}
+
+/// Whether `left + right == total` for Pedersen commitments
+pub fn sums_to(
+    left: &[u8; COMMITMENT_LEN],
+    right: &[u8; COMMITMENT_LEN],
+    total: &[u8; COMMITMENT_LEN],
+) -> bool {
+    add_ristretto(&PodRistrettoPoint(*left), &PodRistrettoPoint(*right))
+        .is_some_and(|point| point.0 == *total)
+}
+
+/// Little-endian scalar encoding of `value`
+fn scalar(value: u64) -> PodScalar {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&value.to_le_bytes());
+    PodScalar(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Payroll;
+
+    /// Commitment to `value` with blinding factor `opening`
+    fn commit(value: i128, opening: u64) -> [u8; COMMITMENT_LEN] {
+        let positive = multiscalar_multiply_ristretto(
+            &[scalar(value.unsigned_abs() as u64), scalar(opening)],
+            &[PodRistrettoPoint(PEDERSEN_G), PodRistrettoPoint(PEDERSEN_H)],
+        )
+        .unwrap();
+        if value >= 0 {
+            return positive.0;
+        }
+
+        // -v * G + r * H = (r * H - (v * G + r * H)) + r * H
+        let blinding =
+            multiply_ristretto(&scalar(opening), &PodRistrettoPoint(PEDERSEN_H)).unwrap();
+        let negated = subtract_ristretto(&blinding, &positive).unwrap();
+        add_ristretto(&negated, &blinding).unwrap().0
+    }
+
+    fn payroll(tax_rate_bps: u16) -> Payroll {
+        Payroll {
+            tax_rate_bps,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn generators_match_commitments() {
+        assert_eq!(commit(1, 0), PEDERSEN_G);
+        assert_eq!(commit(0, 1), PEDERSEN_H);
+    }
+
+    #[test]
+    fn opens_to_known_commitment() {
+        let commitment = commit(42, 7);
+        assert!(opens_to(&commitment, 42, &scalar(7).0));
+        assert!(!opens_to(&commitment, 41, &scalar(7).0));
+        assert!(!opens_to(&commitment, 42, &scalar(8).0));
+    }
+
+    #[test]
+    fn sums_to_adds_values_and_openings() {
+        assert!(sums_to(&commit(30, 3), &commit(12, 4), &commit(42, 7)));
+        assert!(!sums_to(&commit(30, 3), &commit(12, 4), &commit(43, 7)));
+        assert!(!sums_to(&commit(30, 3), &commit(12, 4), &commit(42, 8)));
+    }
+
+    #[test]
+    fn chunks_recombine_to_amount() {
+        let amount: u64 = (5 << 32) + 17;
+        let total = commit(amount as i128, 1 + (2 << 32));
+        assert!(chunks_sum_to(&commit(17, 1), &commit(5, 2), &total));
+        assert!(!chunks_sum_to(&commit(17, 1), &commit(5, 2), &commit(amount as i128, 3)));
+    }
+
+    #[test]
+    fn tax_bounds_accept_only_half_up_tax() {
+        // (tax_rate_bps, salary): exact halves round up, just-below halves
+        // round down, plus ordinary and zero-rate cases
+        let cases = [(500, 10), (500, 9), (1, 5_000), (1, 4_999), (1_234, 98_765), (0, 1_000)];
+
+        for (tax_rate_bps, salary) in cases {
+            let required = payroll(tax_rate_bps).required_tax(salary).unwrap();
+
+            for tax in required.saturating_sub(1)..=required + 1 {
+                let Some(net) = salary.checked_sub(tax) else {
+                    continue;
+                };
+                let bounds = tax_bound_commitments(
+                    &commit(net as i128, 0),
+                    &commit(tax as i128, 0),
+                    tax_rate_bps,
+                )
+                .unwrap();
+
+                let lower = 10_000 * tax as i128 - tax_rate_bps as i128 * salary as i128 + 4_999;
+                let upper = 9_999 - lower;
+                assert_eq!(bounds, [commit(lower, 0), commit(upper, 0)]);
+
+                // Both bounds are provably non-negative exactly for the
+                // half-up tax
+                let in_range = lower >= 0 && upper >= 0;
+                assert_eq!(
+                    in_range,
+                    tax == required,
+                    "rate {tax_rate_bps}, salary {salary}, tax {tax}"
+                );
+            }
+        }
+    }
+}
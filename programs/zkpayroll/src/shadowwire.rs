@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+/// Discriminator of a ShadowWire confidential account
+/// (`sha256("account:ConfidentialAccount")[..8]`)
+pub const CONFIDENTIAL_ACCOUNT_DISCRIMINATOR: [u8; 8] = [200, 15, 153, 251, 95, 46, 218, 67];
+
+/// Offset of the owner wallet inside a ShadowWire confidential account
+const OWNER_OFFSET: usize = 8;
+const OWNER_LEN: usize = 32;
+
+/// Owner wallet of the ShadowWire confidential account `info`
+///
+/// Returns `None` unless `info` is owned by `shadowwire_program` and its
+/// data starts with the confidential account discriminator followed by
+/// the owner wallet:
+/// `discriminator (8) || owner (32) || ...`
+pub fn confidential_account_owner(
+    info: &AccountInfo,
+    shadowwire_program: &Pubkey,
+) -> Option<Pubkey> {
+    if info.owner != shadowwire_program {
+        return None;
+    }
+
+    let data = info.try_borrow_data().ok()?;
+    if data.get(..OWNER_OFFSET)? != CONFIDENTIAL_ACCOUNT_DISCRIMINATOR {
+        return None;
+    }
+    let owner = data.get(OWNER_OFFSET..OWNER_OFFSET + OWNER_LEN)?;
+    Pubkey::try_from(owner).ok()
+}
+
+/// Whether `info` is a ShadowWire confidential account belonging to `wallet`
+pub fn is_confidential_account_of(
+    info: &AccountInfo,
+    shadowwire_program: &Pubkey,
+    wallet: &Pubkey,
+) -> bool {
+    confidential_account_owner(info, shadowwire_program).as_ref() == Some(wallet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check `data` held by an account owned by `owner` against `program`
+    fn owner_of(owner: Pubkey, program: Pubkey, mut data: Vec<u8>) -> Option<Pubkey> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        confidential_account_owner(&info, &program)
+    }
+
+    fn account_data(wallet: &Pubkey) -> Vec<u8> {
+        let mut data = CONFIDENTIAL_ACCOUNT_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(wallet.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data
+    }
+
+    #[test]
+    fn reads_owner_wallet() {
+        let program = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        assert_eq!(owner_of(program, program, account_data(&wallet)), Some(wallet));
+    }
+
+    #[test]
+    fn rejects_other_programs_and_layouts() {
+        let program = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+
+        assert_eq!(owner_of(Pubkey::new_unique(), program, account_data(&wallet)), None);
+
+        let mut data = account_data(&wallet);
+        data[0] ^= 1;
+        assert_eq!(owner_of(program, program, data), None);
+
+        let data = account_data(&wallet)[..OWNER_OFFSET + OWNER_LEN - 1].to_vec();
+        assert_eq!(owner_of(program, program, data), None);
+        assert_eq!(owner_of(program, program, Vec::new()), None);
+    }
+}
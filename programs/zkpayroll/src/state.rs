@@ -1,14 +1,28 @@
 use anchor_lang::prelude::*;
+use crate::errors::PayrollError;
 
 /// Payroll account - stores employer configuration
-/// PDA Seeds: ["payroll", authority.as_ref()]
+/// PDA Seeds: ["payroll", creator.as_ref(), payroll_id.to_le_bytes()]
+/// Treasury vault (token account owned by this PDA) seeds: ["treasury", payroll.key().as_ref()]
+/// Confidential treasury vault (Token-2022 account owned by this PDA) seeds:
+/// ["confidential_treasury", payroll.key().as_ref()]
 #[account]
 #[derive(Default)]
 pub struct Payroll {
+    /// Wallet that created the payroll (PDA seed, fixed across authority
+    /// transfers)
+    pub creator: Pubkey,
+    /// Payroll identifier, unique per creator (PDA seed)
+    pub payroll_id: u64,
     /// Employer wallet address (authority)
     pub authority: Pubkey,
+    /// Proposed new authority, pending acceptance
+    pub pending_authority: Option<Pubkey>,
     /// Tax authority wallet address (government)
     pub tax_authority: Pubkey,
+    /// Tax authority's ShadowWire confidential account (receives withheld
+    /// tax on ShadowWire payments)
+    pub tax_confidential_account: Pubkey,
     /// Tax rate in basis points (500 = 5%)
     pub tax_rate_bps: u16,
     /// ShadowWire program ID for CPI calls
@@ -19,17 +33,481 @@ pub struct Payroll {
     pub employee_count: u32,
     /// Total payments processed
     pub payment_count: u64,
+    /// Notice period before a proposed config change can be applied
+    pub config_delay_seconds: i64,
+    /// Config change waiting for its timelock to expire
+    pub pending_config: Option<PendingConfig>,
+    /// M-of-N approval policy for payments (None = single signature)
+    pub approval_policy: Option<ApprovalPolicy>,
+    /// Minimum screening score (0-100) employees must hold
+    pub screening_threshold: u8,
+    /// How long a screening stays valid, in seconds
+    pub screening_validity_seconds: i64,
+    /// Current pay period (starts at 1, only ever increases)
+    pub current_period: u64,
+    /// Unix timestamp at which the current pay period started
+    pub period_started_at: i64,
+    /// Minimum length of a pay period before it can be advanced
+    pub min_period_seconds: i64,
+    /// Mint held by the treasury vault (default until the vault is created)
+    pub treasury_mint: Pubkey,
+    /// Treasury vault PDA bump seed
+    pub treasury_bump: u8,
+    /// ElGamal public keys of auditors that payment amounts are disclosed to
+    pub auditors: Vec<[u8; 32]>,
+    /// Open payroll run for the current period; payments for the period
+    /// must count towards it
+    pub active_run: Option<Pubkey>,
+    /// Funded amount of the active run not yet paid out (cannot be withdrawn)
+    pub reserved_amount: u64,
+    /// Mint held by the confidential treasury vault (default until the vault is created)
+    pub confidential_mint: Pubkey,
+    /// Confidential treasury vault PDA bump seed
+    pub confidential_treasury_bump: u8,
+    /// Number of payroll runs opened so far (seeds the next run's PDA)
+    pub run_count: u64,
 }
 
 impl Payroll {
     pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        8 +  // payroll_id
         32 + // authority
+        1 + 32 + // pending_authority (Option)
         32 + // tax_authority  
+        32 + // tax_confidential_account
         2 +  // tax_rate_bps
         32 + // shadowwire_program
         1 +  // bump
         4 +  // employee_count
-        8;   // payment_count
+        8 +  // payment_count
+        8 +  // config_delay_seconds
+        1 + PendingConfig::LEN + // pending_config (Option)
+        1 + ApprovalPolicy::LEN + // approval_policy (Option)
+        1 +  // screening_threshold
+        8 +  // screening_validity_seconds
+        8 +  // current_period
+        8 +  // period_started_at
+        8 +  // min_period_seconds
+        32 + // treasury_mint
+        1 +  // treasury_bump
+        4 + Self::MAX_AUDITORS * 32 + // auditors (Vec with length prefix)
+        1 + 32 + // active_run (Option)
+        8 +  // reserved_amount
+        32 + // confidential_mint
+        1 +  // confidential_treasury_bump
+        8;   // run_count
+
+    /// Maximum number of registered auditor keys
+    pub const MAX_AUDITORS: usize = 4;
+
+    /// Basis-point denominator (10000 bps = 100%)
+    pub const BPS_DENOMINATOR: u128 = 10_000;
+
+    /// Tax that must be withheld from `salary_amount` at `tax_rate_bps`
+    ///
+    /// Rounding policy: round half up to the nearest base unit, i.e.
+    /// `floor((salary_amount * tax_rate_bps + 5000) / 10000)`.
+    pub fn required_tax(&self, salary_amount: u64) -> Result<u64> {
+        let scaled = (salary_amount as u128)
+            .checked_mul(self.tax_rate_bps as u128)
+            .and_then(|v| v.checked_add(Self::BPS_DENOMINATOR / 2))
+            .ok_or(PayrollError::InvalidAmount)?;
+        u64::try_from(scaled / Self::BPS_DENOMINATOR)
+            .map_err(|_| error!(PayrollError::InvalidAmount))
+    }
+
+    /// Check that a payment for `period` counts towards the active payroll
+    /// run, if one is open for the current period
+    pub fn check_run(&self, period: u64, run: Option<Pubkey>) -> Result<()> {
+        if let Some(active_run) = self.active_run {
+            if period == self.current_period {
+                require!(run == Some(active_run), PayrollError::RunRequired);
+            }
+        }
+        Ok(())
+    }
+
+    /// Treasury funds a plaintext payment of `payment_type` may draw on
+    ///
+    /// Regular payments draw on the active run's reservation; off-cycle
+    /// payments only on the balance left unreserved.
+    pub fn available_for(&self, payment_type: PaymentType, treasury_balance: u64) -> u64 {
+        match payment_type {
+            PaymentType::Regular => treasury_balance,
+            PaymentType::OffCycle { .. } => treasury_balance.saturating_sub(self.reserved_amount),
+        }
+    }
+
+    /// Clear the active payroll run and release its reserved funds
+    pub fn release_run(&mut self) {
+        self.active_run = None;
+        self.reserved_amount = 0;
+    }
+
+    /// Check that `employee` holds a passing, unexpired screening under
+    /// this payroll's policy; returns seconds since the last screening
+    pub fn check_screening(&self, employee: &Employee, now: i64) -> Result<i64> {
+        require!(
+            employee.screening_score >= self.screening_threshold,
+            PayrollError::ScreeningFailed
+        );
+        let age = now - employee.last_screened;
+        require!(
+            age < self.screening_validity_seconds,
+            PayrollError::ScreeningExpired
+        );
+        Ok(age)
+    }
+}
+
+/// Proposed payroll configuration, applied after the timelock expires
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PendingConfig {
+    /// New tax rate in basis points
+    pub tax_rate_bps: u16,
+    /// New tax authority wallet address
+    pub tax_authority: Pubkey,
+    /// New tax authority ShadowWire confidential account
+    pub tax_confidential_account: Pubkey,
+    /// New ShadowWire program ID
+    pub shadowwire_program: Pubkey,
+    /// New notice period for future config changes
+    pub config_delay_seconds: i64,
+    /// New minimum pay period length
+    pub min_period_seconds: i64,
+    /// Unix timestamp from which the change can be applied
+    pub effective_at: i64,
+}
+
+impl PendingConfig {
+    pub const LEN: usize = 2 + // tax_rate_bps
+        32 + // tax_authority
+        32 + // tax_confidential_account
+        32 + // shadowwire_program
+        8 +  // config_delay_seconds
+        8 +  // min_period_seconds
+        8;   // effective_at
+}
+
+/// M-of-N approval policy for payroll payments
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ApprovalPolicy {
+    /// Wallets allowed to approve payments
+    pub approvers: Vec<Pubkey>,
+    /// Approvals required before a payment can execute
+    pub threshold: u8,
+}
+
+impl ApprovalPolicy {
+    pub const MAX_APPROVERS: usize = 10;
+
+    pub const LEN: usize = 4 + Self::MAX_APPROVERS * 32 + // approvers (Vec with length prefix)
+        1;   // threshold
+
+    /// Whether `wallet` is a configured approver
+    pub fn is_approver(&self, wallet: &Pubkey) -> bool {
+        self.approvers.contains(wallet)
+    }
+
+    /// Number of `approvals` that come from current approvers
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|a| self.is_approver(a)).count()
+    }
+}
+
+/// Delegated payroll role
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// Adds and deactivates employees
+    HrAdmin,
+    /// Runs payments
+    PayrollOperator,
+    /// Updates screening scores
+    ComplianceOfficer,
+}
+
+impl Role {
+    /// Bit used for this role in `RoleAssignment::roles`
+    pub fn mask(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+/// Roles held by a single member
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RoleAssignment {
+    /// Member wallet address
+    pub member: Pubkey,
+    /// Bitmask of granted roles (see `Role::mask`)
+    pub roles: u8,
+}
+
+impl RoleAssignment {
+    pub const LEN: usize = 32 + // member
+        1;   // roles
+}
+
+/// Role registry - delegated roles for a payroll
+/// PDA Seeds: ["roles", payroll.key().as_ref()]
+#[account]
+#[derive(Default)]
+pub struct RoleRegistry {
+    /// Reference to parent payroll account
+    pub payroll: Pubkey,
+    /// Members and their granted roles
+    pub members: Vec<RoleAssignment>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl RoleRegistry {
+    pub const MAX_MEMBERS: usize = 16;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // payroll
+        4 + Self::MAX_MEMBERS * RoleAssignment::LEN + // members (Vec with length prefix)
+        1;   // bump
+
+    /// Whether `member` has been granted `role`
+    pub fn has_role(&self, member: &Pubkey, role: Role) -> bool {
+        self.members
+            .iter()
+            .any(|m| m.member == *member && m.roles & role.mask() != 0)
+    }
+
+    /// Whether `signer` may act as `role` on `payroll`
+    /// The payroll authority implicitly holds every role
+    pub fn authorizes(&self, payroll: &Payroll, signer: &Pubkey, role: Role) -> bool {
+        *signer == payroll.authority || self.has_role(signer, role)
+    }
+
+    /// Grant `role` to `member`, adding the member if needed
+    pub fn grant(&mut self, member: Pubkey, role: Role) -> Result<()> {
+        if let Some(entry) = self.members.iter_mut().find(|m| m.member == member) {
+            entry.roles |= role.mask();
+            return Ok(());
+        }
+
+        require!(
+            self.members.len() < Self::MAX_MEMBERS,
+            PayrollError::RoleRegistryFull
+        );
+        self.members.push(RoleAssignment {
+            member,
+            roles: role.mask(),
+        });
+        Ok(())
+    }
+
+    /// Revoke `role` from `member`, removing the member once no roles remain
+    pub fn revoke(&mut self, member: &Pubkey, role: Role) -> Result<()> {
+        let index = self
+            .members
+            .iter()
+            .position(|m| m.member == *member && m.roles & role.mask() != 0)
+            .ok_or(PayrollError::RoleNotGranted)?;
+
+        self.members[index].roles &= !role.mask();
+        if self.members[index].roles == 0 {
+            self.members.swap_remove(index);
+        }
+        Ok(())
+    }
+}
+
+/// Kind of payment, which determines the payment record's PDA
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PaymentType {
+    /// Scheduled salary payment (at most one per employee per period)
+    #[default]
+    Regular,
+    /// Bonus or correction paid outside the regular schedule
+    /// `index` must exceed the employee's last off-cycle index
+    OffCycle { index: u32 },
+}
+
+impl PaymentType {
+    pub const LEN: usize = 1 + 4; // variant tag + largest variant
+
+    /// Index seeding the payment record PDA (0 for regular payments)
+    pub fn record_index(&self) -> u32 {
+        match self {
+            PaymentType::Regular => 0,
+            PaymentType::OffCycle { index } => *index,
+        }
+    }
+}
+
+/// Lifecycle of a payroll run
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RunStatus {
+    /// Created, waiting for the treasury to be funded
+    #[default]
+    Open,
+    /// Treasury funds reserved for the run
+    Funded,
+    /// At least one regular payment has been made
+    Executing,
+    /// Every expected regular payment has been made
+    Completed,
+    /// Cancelled before completing; kept as a record of the payments made
+    Cancelled,
+}
+
+/// Payroll run - tracks one pay period's payments across transactions
+/// Cancelled runs are kept, and a new run can be opened for the period
+/// PDA Seeds: ["run", payroll.key().as_ref(), &payroll.run_count.to_le_bytes()]
+#[account]
+#[derive(Default)]
+pub struct PayrollRun {
+    /// Reference to parent payroll account
+    pub payroll: Pubkey,
+    /// Pay period the run pays out
+    pub period: u64,
+    /// Current lifecycle state
+    pub status: RunStatus,
+    /// Number of regular payments the run is expected to make
+    pub expected_count: u32,
+    /// Number of regular payments made so far
+    pub processed_count: u32,
+    /// Number of off-cycle payments made for the period (never completes
+    /// the run)
+    pub off_cycle_count: u32,
+    /// Treasury vault funds reserved for the run's treasury payments when
+    /// it was funded (confidential payments are backed by the locked
+    /// confidential treasury instead)
+    pub funded_amount: u64,
+    /// Operator who opened the run
+    pub opened_by: Pubkey,
+    /// Unix timestamp at which the run was opened
+    pub opened_at: i64,
+    /// Unix timestamp at which the run completed (0 until then)
+    pub completed_at: i64,
+    /// Unix timestamp at which the run was cancelled (0 unless cancelled)
+    pub cancelled_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PayrollRun {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // payroll
+        8 +  // period
+        1 +  // status
+        4 +  // expected_count
+        4 +  // processed_count
+        4 +  // off_cycle_count
+        8 +  // funded_amount
+        32 + // opened_by
+        8 +  // opened_at
+        8 +  // completed_at
+        8 +  // cancelled_at
+        1;   // bump
+
+    /// Count `count` payments of `payment_type` for `period` against the run
+    ///
+    /// Regular payments complete the run once every expected payment has
+    /// been made. Off-cycle payments are counted separately, so bonuses
+    /// cannot complete a run before everyone has been paid.
+    pub fn record_payments(
+        &mut self,
+        period: u64,
+        payment_type: PaymentType,
+        count: u32,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            matches!(self.status, RunStatus::Funded | RunStatus::Executing),
+            PayrollError::InvalidRunStatus
+        );
+        require!(period == self.period, PayrollError::RunPeriodMismatch);
+
+        if payment_type != PaymentType::Regular {
+            self.off_cycle_count = self
+                .off_cycle_count
+                .checked_add(count)
+                .ok_or(PayrollError::RunCountExceeded)?;
+            return Ok(());
+        }
+
+        let processed_count = self
+            .processed_count
+            .checked_add(count)
+            .filter(|c| *c <= self.expected_count)
+            .ok_or(PayrollError::RunCountExceeded)?;
+
+        self.processed_count = processed_count;
+        if processed_count == self.expected_count {
+            self.status = RunStatus::Completed;
+            self.completed_at = now;
+        } else {
+            self.status = RunStatus::Executing;
+        }
+        Ok(())
+    }
+
+    /// Cancel a run that has not finished
+    pub fn cancel(&mut self, now: i64) -> Result<()> {
+        require!(
+            !matches!(self.status, RunStatus::Completed | RunStatus::Cancelled),
+            PayrollError::InvalidRunStatus
+        );
+        self.status = RunStatus::Cancelled;
+        self.cancelled_at = now;
+        Ok(())
+    }
+}
+
+/// Pedersen commitments to a payment's net salary and withheld tax
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PaymentCommitments {
+    /// Commitment to the net salary paid to the employee
+    pub net: [u8; 32],
+    /// Commitment to the tax withheld
+    pub tax: [u8; 32],
+}
+
+impl PaymentCommitments {
+    pub const LEN: usize = 32 + // net
+        32;  // tax
+}
+
+/// Blinding factors opening a payment's commitments to its plaintext amounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PaymentOpenings {
+    /// Opening of the net salary commitment
+    pub net: [u8; 32],
+    /// Opening of the tax commitment
+    pub tax: [u8; 32],
+}
+
+impl PaymentOpenings {
+    pub const LEN: usize = 32 + // net
+        32;  // tax
+}
+
+/// Screening provider - a key allowed to sign screening attestations
+/// PDA Seeds: ["screening_provider", payroll.key().as_ref(), provider.as_ref()]
+#[account]
+#[derive(Default)]
+pub struct ScreeningProvider {
+    /// Reference to parent payroll account
+    pub payroll: Pubkey,
+    /// Provider's Ed25519 signing key
+    pub provider: Pubkey,
+    /// Unix timestamp of registration
+    pub registered_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ScreeningProvider {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // payroll
+        32 + // provider
+        8 +  // registered_at
+        1;   // bump
 }
 
 /// Employee account - stores employee information
@@ -46,17 +524,27 @@ pub struct Employee {
     /// Employee's Solana wallet address
     pub wallet: Pubkey,
     /// Pedersen commitment of salary (hides actual amount)
+    /// Most recently recorded; payments check the commitment in force for
+    /// their period (see `SalaryHistory::commitment_for`)
     pub salary_commitment: [u8; 32],
     /// Risk screening score from Range API (0-100)
     pub screening_score: u8,
-    /// Unix timestamp of last screening
+    /// Unix timestamp of last screening (as attested by the provider)
     pub last_screened: i64,
+    /// Screening provider that signed the last screening attestation
+    pub screening_provider: Pubkey,
     /// Whether employee is active
     pub is_active: bool,
+    /// Unix timestamp of deactivation (0 while active)
+    pub deactivated_at: i64,
     /// Employee's ShadowWire confidential account
     pub confidential_account: Pubkey,
     /// PDA bump seed
     pub bump: u8,
+    /// Last pay period a regular payment was made for (0 = never paid)
+    pub last_paid_period: u64,
+    /// Index of the last off-cycle payment (0 = none)
+    pub last_off_cycle_index: u32,
 }
 
 impl Employee {
@@ -71,24 +559,330 @@ impl Employee {
         32 + // salary_commitment
         1 +  // screening_score
         8 +  // last_screened
+        32 + // screening_provider
         1 +  // is_active
+        8 +  // deactivated_at
         32 + // confidential_account
+        1 +  // bump
+        8 +  // last_paid_period
+        4;   // last_off_cycle_index
+
+    /// Claim the payment slot for `payment_type` in `period`
+    /// A period gets one regular payment; off-cycle indexes only increase
+    pub fn record_payment(&mut self, period: u64, payment_type: PaymentType) -> Result<()> {
+        match payment_type {
+            PaymentType::Regular => {
+                require!(
+                    period > self.last_paid_period,
+                    PayrollError::AlreadyPaidForPeriod
+                );
+                self.last_paid_period = period;
+            }
+            PaymentType::OffCycle { index } => {
+                require!(
+                    index > self.last_off_cycle_index,
+                    PayrollError::InvalidOffCycleIndex
+                );
+                self.last_off_cycle_index = index;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Employee invite - an employee record awaiting the employee's acceptance
+/// PDA Seeds: ["invite", payroll.key().as_ref(), employee_id.as_bytes()]
+#[account]
+#[derive(Default)]
+pub struct EmployeeInvite {
+    /// Reference to parent payroll account
+    pub payroll: Pubkey,
+    /// Employee ID the accepted employee account is created under
+    pub employee_id: String,
+    /// Employee name
+    pub name: String,
+    /// Wallet that must accept the invite
+    pub wallet: Pubkey,
+    /// Pedersen commitment of salary
+    pub salary_commitment: [u8; 32],
+    /// HR admin who created the invite (receives its rent on acceptance)
+    pub created_by: Pubkey,
+    /// Unix timestamp of creation
+    pub created_at: i64,
+    /// Lamports held beyond rent to cover the employee's account rent
+    pub prefunded_lamports: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl EmployeeInvite {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // payroll
+        4 + Employee::MAX_ID_LEN +   // employee_id (String with length prefix)
+        4 + Employee::MAX_NAME_LEN + // name (String with length prefix)
+        32 + // wallet
+        32 + // salary_commitment
+        32 + // created_by
+        8 +  // created_at
+        8 +  // prefunded_lamports
+        1;   // bump
+}
+
+/// A single screening result in an employee's history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ScreeningEntry {
+    /// Risk screening score (0-100)
+    pub score: u8,
+    /// Unix timestamp of the screening (as attested by the provider)
+    pub screened_at: i64,
+    /// Screening provider that signed the attestation
+    pub provider: Pubkey,
+    /// Signer that submitted the attestation
+    pub submitted_by: Pubkey,
+    /// Unix timestamp at which the entry was recorded
+    pub recorded_at: i64,
+}
+
+impl ScreeningEntry {
+    pub const LEN: usize = 1 +  // score
+        8 +  // screened_at
+        32 + // provider
+        32 + // submitted_by
+        8;   // recorded_at
+}
+
+/// Screening history - ring buffer of an employee's screening results
+/// PDA Seeds: ["screening_history", employee.key().as_ref()]
+#[account]
+#[derive(Default)]
+pub struct ScreeningHistory {
+    /// Reference to the employee account
+    pub employee: Pubkey,
+    /// Most recent entries; once full, the oldest entry is overwritten
+    pub entries: Vec<ScreeningEntry>,
+    /// Total number of entries ever recorded
+    pub total_entries: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ScreeningHistory {
+    pub const MAX_ENTRIES: usize = 32;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // employee
+        4 + Self::MAX_ENTRIES * ScreeningEntry::LEN + // entries (Vec with length prefix)
+        8 +  // total_entries
         1;   // bump
+
+    /// Append an entry, overwriting the oldest one once the buffer is full
+    pub fn record(&mut self, entry: ScreeningEntry) {
+        ring_record(&mut self.entries, &mut self.total_entries, Self::MAX_ENTRIES, entry);
+    }
+
+    /// Entries from oldest to newest
+    pub fn chronological(&self) -> impl Iterator<Item = &ScreeningEntry> {
+        ring_chronological(&self.entries, self.total_entries, Self::MAX_ENTRIES)
+    }
+}
+
+/// Commitments to the 32-bit low and high chunks of a payment's amounts
+///
+/// ElGamal amounts are only decryptable when small, so amounts are
+/// disclosed to auditors in chunks with `C_lo + 2^32 * C_hi = C`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct AuditChunks {
+    /// Low 32 bits of the net salary
+    pub net_lo: [u8; 32],
+    /// High 32 bits of the net salary
+    pub net_hi: [u8; 32],
+    /// Low 32 bits of the tax
+    pub tax_lo: [u8; 32],
+    /// High 32 bits of the tax
+    pub tax_hi: [u8; 32],
+}
+
+impl AuditChunks {
+    pub const LEN: usize = 32 + // net_lo
+        32 + // net_hi
+        32 + // tax_lo
+        32;  // tax_hi
+}
+
+/// Amounts of one payment encrypted to one auditor
+///
+/// Together with the audit's chunk commitments these form twisted ElGamal
+/// ciphertexts `(commitment, handle)` that only the auditor can decrypt.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AuditEntry {
+    /// Auditor ElGamal public key
+    pub auditor: [u8; 32],
+    /// Decrypt handle for the low chunk of the net salary
+    pub net_lo_handle: [u8; 32],
+    /// Decrypt handle for the high chunk of the net salary
+    pub net_hi_handle: [u8; 32],
+    /// Decrypt handle for the low chunk of the tax
+    pub tax_lo_handle: [u8; 32],
+    /// Decrypt handle for the high chunk of the tax
+    pub tax_hi_handle: [u8; 32],
+}
+
+impl AuditEntry {
+    pub const LEN: usize = 32 + // auditor
+        32 + // net_lo_handle
+        32 + // net_hi_handle
+        32 + // tax_lo_handle
+        32;  // tax_hi_handle
+}
+
+/// Payment audit - payment amounts disclosed to the payroll's auditors
+/// PDA Seeds: ["audit", payment_record.key().as_ref()]
+#[account]
+#[derive(Default)]
+pub struct PaymentAudit {
+    /// Reference to the payroll account
+    pub payroll: Pubkey,
+    /// Payment record the amounts belong to
+    pub payment_record: Pubkey,
+    /// Commitments to the disclosed amount chunks
+    pub chunks: AuditChunks,
+    /// One entry per auditor in the payment record's snapshot
+    pub entries: Vec<AuditEntry>,
+    /// Unix timestamp at which the audit was recorded
+    pub recorded_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PaymentAudit {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // payroll
+        32 + // payment_record
+        AuditChunks::LEN + // chunks
+        4 + Payroll::MAX_AUDITORS * AuditEntry::LEN + // entries (Vec with length prefix)
+        8 +  // recorded_at
+        1;   // bump
+}
+
+/// A single salary commitment change in an employee's history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SalaryEntry {
+    /// Pedersen salary commitment
+    pub commitment: [u8; 32],
+    /// First pay period the commitment applies to
+    pub effective_period: u64,
+    /// HR admin who made the change
+    pub changed_by: Pubkey,
+    /// Whether the employee co-signed the change
+    pub employee_signed: bool,
+    /// Unix timestamp at which the change was recorded
+    pub recorded_at: i64,
+}
+
+impl SalaryEntry {
+    pub const LEN: usize = 32 + // commitment
+        8 +  // effective_period
+        32 + // changed_by
+        1 +  // employee_signed
+        8;   // recorded_at
+}
+
+/// Salary history - ring buffer of an employee's salary commitments
+/// PDA Seeds: ["salary_history", employee.key().as_ref()]
+#[account]
+#[derive(Default)]
+pub struct SalaryHistory {
+    /// Reference to the employee account
+    pub employee: Pubkey,
+    /// Most recent entries; once full, the oldest entry is overwritten
+    pub entries: Vec<SalaryEntry>,
+    /// Total number of entries ever recorded
+    pub total_entries: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SalaryHistory {
+    pub const MAX_ENTRIES: usize = 24;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // employee
+        4 + Self::MAX_ENTRIES * SalaryEntry::LEN + // entries (Vec with length prefix)
+        8 +  // total_entries
+        1;   // bump
+
+    /// Append an entry, overwriting the oldest one once the buffer is full
+    pub fn record(&mut self, entry: SalaryEntry) {
+        ring_record(&mut self.entries, &mut self.total_entries, Self::MAX_ENTRIES, entry);
+    }
+
+    /// Entries from oldest to newest
+    pub fn chronological(&self) -> impl Iterator<Item = &SalaryEntry> {
+        ring_chronological(&self.entries, self.total_entries, Self::MAX_ENTRIES)
+    }
+
+    /// Salary commitment in force for `period`: the most recently recorded
+    /// entry that is effective by then
+    pub fn commitment_for(&self, period: u64) -> Result<[u8; 32]> {
+        self.chronological()
+            .filter(|entry| entry.effective_period <= period)
+            .last()
+            .map(|entry| entry.commitment)
+            .ok_or_else(|| error!(PayrollError::NoSalaryCommitment))
+    }
+}
+
+/// Append `entry` to a ring buffer of `capacity` entries that has had
+/// `total` entries recorded, overwriting the oldest one once it is full
+fn ring_record<T>(entries: &mut Vec<T>, total: &mut u64, capacity: usize, entry: T) {
+    if entries.len() < capacity {
+        entries.push(entry);
+    } else {
+        let index = (*total % capacity as u64) as usize;
+        entries[index] = entry;
+    }
+    *total = total.saturating_add(1);
+}
+
+/// Entries of a ring buffer of `capacity` entries that has had `total`
+/// entries recorded, from oldest to newest
+fn ring_chronological<T>(entries: &[T], total: u64, capacity: usize) -> impl Iterator<Item = &T> {
+    let oldest = if entries.len() < capacity {
+        0
+    } else {
+        (total % capacity as u64) as usize
+    };
+    entries[oldest..].iter().chain(entries[..oldest].iter())
 }
 
 /// Payment record - stores payment metadata (NO amounts for privacy!)
-/// PDA Seeds: ["payment", employee.key().as_ref(), &timestamp.to_le_bytes()]
+/// PDA Seeds: ["payment", employee.key().as_ref(), &period.to_le_bytes(), &payment_type.record_index().to_le_bytes()]
 #[account]
 #[derive(Default)]
 pub struct PaymentRecord {
+    /// Reference to parent payroll account
+    pub payroll: Pubkey,
     /// Reference to employee account
     pub employee: Pubkey,
-    /// Payment timestamp
+    /// Unix timestamp at which the payment was made
     pub timestamp: i64,
+    /// Pay period the payment belongs to
+    pub period: u64,
+    /// Regular or off-cycle payment
+    pub payment_type: PaymentType,
+    /// Payroll run the payment belongs to (default if paid outside a run)
+    pub run: Pubkey,
+    /// Commitments to the net salary and tax (checked by verify_proof)
+    pub commitments: PaymentCommitments,
+    /// Tax rate in effect when the payment was made
+    pub tax_rate_bps: u16,
     /// Tax authority's ShadowWire confidential account
     pub tax_confidential_account: Pubkey,
     /// Employee's ShadowWire confidential account
     pub employee_confidential_account: Pubkey,
+    /// Auditor keys registered when the payment was made (the amounts must
+    /// be disclosed to each of them)
+    pub auditors: Vec<[u8; 32]>,
     /// Whether the Bulletproof has been verified
     pub verified: bool,
     /// Verification timestamp (0 if not verified)
@@ -101,16 +895,93 @@ pub struct PaymentRecord {
 
 impl PaymentRecord {
     pub const LEN: usize = 8 +  // discriminator
+        32 + // payroll
         32 + // employee
         8 +  // timestamp
+        8 +  // period
+        PaymentType::LEN + // payment_type
+        32 + // run
+        PaymentCommitments::LEN + // commitments
+        2 +  // tax_rate_bps
         32 + // tax_confidential_account
         32 + // employee_confidential_account
+        4 + Payroll::MAX_AUDITORS * 32 + // auditors (Vec with length prefix)
         1 +  // verified
         8 +  // verified_at
         32 + // verifier
         1;   // bump
 }
 
+/// Payment proposal - a payment awaiting M-of-N approval
+/// PDA Seeds: ["proposal", employee.key().as_ref(), &period.to_le_bytes(), &payment_type.record_index().to_le_bytes()]
+#[account]
+#[derive(Default)]
+pub struct PaymentProposal {
+    /// Reference to parent payroll account
+    pub payroll: Pubkey,
+    /// Reference to employee account
+    pub employee: Pubkey,
+    /// Operator who proposed the payment (receives rent on execution)
+    pub proposer: Pubkey,
+    /// Gross salary amount (0 for confidential proposals)
+    pub salary_amount: u64,
+    /// Tax withheld from the salary (0 for confidential proposals)
+    pub tax_amount: u64,
+    /// Pay period the payment belongs to (seeds the resulting payment record)
+    pub period: u64,
+    /// Regular or off-cycle payment (seeds the resulting payment record)
+    pub payment_type: PaymentType,
+    /// Commitments to the net salary and tax
+    pub commitments: PaymentCommitments,
+    /// Blinding factors opening the commitments to the amounts (default for
+    /// confidential proposals)
+    pub openings: PaymentOpenings,
+    /// Whether the payment is made by confidential transfer; only the
+    /// commitments are known until it executes
+    pub confidential: bool,
+    /// Approvers who have signed off
+    pub approvals: Vec<Pubkey>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PaymentProposal {
+    pub const LEN: usize = 8 +  // discriminator
+        32 + // payroll
+        32 + // employee
+        32 + // proposer
+        8 +  // salary_amount
+        8 +  // tax_amount
+        8 +  // period
+        PaymentType::LEN + // payment_type
+        PaymentCommitments::LEN + // commitments
+        PaymentOpenings::LEN + // openings
+        1 +  // confidential
+        4 + ApprovalPolicy::MAX_APPROVERS * 32 + // approvals (Vec with length prefix)
+        1;   // bump
+
+    /// Record `approver`'s approval under `policy`; returns the number of
+    /// approvals
+    ///
+    /// Approvals from wallets rotated out of the policy are dropped first:
+    /// they no longer count, and would otherwise fill the proposal.
+    pub fn approve(&mut self, policy: &ApprovalPolicy, approver: Pubkey) -> Result<usize> {
+        require!(policy.is_approver(&approver), PayrollError::NotApprover);
+        self.approvals.retain(|approval| policy.is_approver(approval));
+        require!(
+            !self.approvals.contains(&approver),
+            PayrollError::AlreadyApproved
+        );
+        require!(
+            self.approvals.len() < ApprovalPolicy::MAX_APPROVERS,
+            PayrollError::ApprovalsFull
+        );
+
+        self.approvals.push(approver);
+        Ok(self.approvals.len())
+    }
+}
+
 /// Employer's confidential account configuration
 #[account]
 #[derive(Default)]
@@ -132,3 +1003,365 @@ impl EmployerConfidentialConfig {
         32 + // tax_confidential_account
         1;   // bump
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROLES: [Role; 3] = [Role::HrAdmin, Role::PayrollOperator, Role::ComplianceOfficer];
+
+    #[test]
+    fn role_masks_are_distinct_bits() {
+        assert_eq!(Role::HrAdmin.mask(), 0b001);
+        assert_eq!(Role::PayrollOperator.mask(), 0b010);
+        assert_eq!(Role::ComplianceOfficer.mask(), 0b100);
+    }
+
+    #[test]
+    fn grant_and_revoke_each_role() {
+        let member = Pubkey::new_unique();
+        let mut registry = RoleRegistry::default();
+
+        for (granted, role) in ROLES.into_iter().enumerate() {
+            registry.grant(member, role).unwrap();
+            assert_eq!(registry.members.len(), 1);
+            for (index, other) in ROLES.into_iter().enumerate() {
+                assert_eq!(registry.has_role(&member, other), index <= granted);
+            }
+        }
+
+        for (revoked, role) in ROLES.into_iter().enumerate() {
+            registry.revoke(&member, role).unwrap();
+            for (index, other) in ROLES.into_iter().enumerate() {
+                assert_eq!(registry.has_role(&member, other), index > revoked);
+            }
+        }
+        assert!(registry.members.is_empty());
+    }
+
+    #[test]
+    fn grant_is_idempotent_and_revoke_requires_role() {
+        let member = Pubkey::new_unique();
+        let mut registry = RoleRegistry::default();
+
+        registry.grant(member, Role::HrAdmin).unwrap();
+        registry.grant(member, Role::HrAdmin).unwrap();
+        assert_eq!(registry.members.len(), 1);
+        assert_eq!(registry.members[0].roles, Role::HrAdmin.mask());
+
+        assert_eq!(
+            registry.revoke(&member, Role::PayrollOperator).unwrap_err(),
+            PayrollError::RoleNotGranted.into()
+        );
+        assert_eq!(
+            registry.revoke(&Pubkey::new_unique(), Role::HrAdmin).unwrap_err(),
+            PayrollError::RoleNotGranted.into()
+        );
+    }
+
+    #[test]
+    fn grant_rejects_members_beyond_capacity() {
+        let mut registry = RoleRegistry::default();
+        for _ in 0..RoleRegistry::MAX_MEMBERS {
+            registry.grant(Pubkey::new_unique(), Role::PayrollOperator).unwrap();
+        }
+
+        assert_eq!(
+            registry.grant(Pubkey::new_unique(), Role::PayrollOperator).unwrap_err(),
+            PayrollError::RoleRegistryFull.into()
+        );
+    }
+
+    #[test]
+    fn authority_holds_every_role() {
+        let payroll = Payroll {
+            authority: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let member = Pubkey::new_unique();
+        let mut registry = RoleRegistry::default();
+        registry.grant(member, Role::ComplianceOfficer).unwrap();
+
+        for role in ROLES {
+            assert!(registry.authorizes(&payroll, &payroll.authority, role));
+            assert_eq!(
+                registry.authorizes(&payroll, &member, role),
+                role == Role::ComplianceOfficer
+            );
+        }
+    }
+
+    /// Ring buffer of `capacity` entries after recording `0..count`
+    fn ring(capacity: usize, count: u64) -> (Vec<u64>, u64) {
+        let mut entries = Vec::new();
+        let mut total = 0;
+        for value in 0..count {
+            ring_record(&mut entries, &mut total, capacity, value);
+        }
+        (entries, total)
+    }
+
+    fn chronological(capacity: usize, count: u64) -> Vec<u64> {
+        let (entries, total) = ring(capacity, count);
+        ring_chronological(&entries, total, capacity).copied().collect()
+    }
+
+    #[test]
+    fn ring_fills_in_order() {
+        assert_eq!(ring(4, 0), (vec![], 0));
+        assert_eq!(ring(4, 3), (vec![0, 1, 2], 3));
+        assert_eq!(ring(4, 4), (vec![0, 1, 2, 3], 4));
+        assert_eq!(chronological(4, 3), vec![0, 1, 2]);
+        assert_eq!(chronological(4, 4), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn ring_overwrites_oldest_once_full() {
+        assert_eq!(ring(4, 5), (vec![4, 1, 2, 3], 5));
+        assert_eq!(ring(4, 7), (vec![4, 5, 6, 3], 7));
+        assert_eq!(ring(4, 8), (vec![4, 5, 6, 7], 8));
+    }
+
+    #[test]
+    fn ring_is_chronological_after_wrapping() {
+        assert_eq!(chronological(4, 5), vec![1, 2, 3, 4]);
+        assert_eq!(chronological(4, 7), vec![3, 4, 5, 6]);
+        assert_eq!(chronological(4, 8), vec![4, 5, 6, 7]);
+        assert_eq!(chronological(4, 10), vec![6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn screening_history_keeps_latest_entries() {
+        let mut history = ScreeningHistory::default();
+        let recorded = ScreeningHistory::MAX_ENTRIES as i64 + 3;
+        for screened_at in 0..recorded {
+            history.record(ScreeningEntry {
+                screened_at,
+                ..Default::default()
+            });
+        }
+
+        assert_eq!(history.entries.len(), ScreeningHistory::MAX_ENTRIES);
+        assert_eq!(history.total_entries, recorded as u64);
+        let screened: Vec<i64> = history.chronological().map(|e| e.screened_at).collect();
+        assert_eq!(screened, (3..recorded).collect::<Vec<_>>());
+    }
+
+    /// Salary history recording `(commitment byte, effective_period)` in order
+    fn salary_history(entries: &[(u8, u64)]) -> SalaryHistory {
+        let mut history = SalaryHistory::default();
+        for &(commitment, effective_period) in entries {
+            history.record(SalaryEntry {
+                commitment: [commitment; 32],
+                effective_period,
+                ..Default::default()
+            });
+        }
+        history
+    }
+
+    #[test]
+    fn no_commitment_before_first_entry() {
+        let history = salary_history(&[(1, 3)]);
+        assert_eq!(
+            history.commitment_for(2).unwrap_err(),
+            PayrollError::NoSalaryCommitment.into()
+        );
+        assert_eq!(
+            SalaryHistory::default().commitment_for(1).unwrap_err(),
+            PayrollError::NoSalaryCommitment.into()
+        );
+    }
+
+    #[test]
+    fn commitment_applies_from_its_effective_period() {
+        let history = salary_history(&[(1, 1), (2, 5)]);
+        assert_eq!(history.commitment_for(1).unwrap(), [1; 32]);
+        assert_eq!(history.commitment_for(4).unwrap(), [1; 32]);
+        assert_eq!(history.commitment_for(5).unwrap(), [2; 32]);
+        assert_eq!(history.commitment_for(9).unwrap(), [2; 32]);
+    }
+
+    #[test]
+    fn backdated_rotation_overrides_earlier_entries() {
+        // Recorded after the period-5 raise, but effective from period 3
+        let history = salary_history(&[(1, 1), (2, 5), (3, 3)]);
+        assert_eq!(history.commitment_for(2).unwrap(), [1; 32]);
+        assert_eq!(history.commitment_for(3).unwrap(), [3; 32]);
+        assert_eq!(history.commitment_for(5).unwrap(), [3; 32]);
+        assert_eq!(history.commitment_for(9).unwrap(), [3; 32]);
+    }
+
+    #[test]
+    fn regular_payment_at_most_once_per_period() {
+        let mut employee = Employee::default();
+        employee.record_payment(1, PaymentType::Regular).unwrap();
+        assert_eq!(employee.last_paid_period, 1);
+
+        assert_eq!(
+            employee.record_payment(1, PaymentType::Regular).unwrap_err(),
+            PayrollError::AlreadyPaidForPeriod.into()
+        );
+        employee.record_payment(3, PaymentType::Regular).unwrap();
+        assert_eq!(
+            employee.record_payment(2, PaymentType::Regular).unwrap_err(),
+            PayrollError::AlreadyPaidForPeriod.into()
+        );
+        assert_eq!(employee.last_paid_period, 3);
+    }
+
+    #[test]
+    fn off_cycle_index_must_increase() {
+        let mut employee = Employee::default();
+        assert_eq!(
+            employee.record_payment(1, PaymentType::OffCycle { index: 0 }).unwrap_err(),
+            PayrollError::InvalidOffCycleIndex.into()
+        );
+
+        employee.record_payment(1, PaymentType::OffCycle { index: 2 }).unwrap();
+        for index in [1, 2] {
+            assert_eq!(
+                employee.record_payment(1, PaymentType::OffCycle { index }).unwrap_err(),
+                PayrollError::InvalidOffCycleIndex.into()
+            );
+        }
+        employee.record_payment(1, PaymentType::OffCycle { index: 3 }).unwrap();
+        assert_eq!(employee.last_off_cycle_index, 3);
+    }
+
+    #[test]
+    fn off_cycle_payments_leave_regular_slot_open() {
+        let mut employee = Employee::default();
+        employee.record_payment(1, PaymentType::OffCycle { index: 1 }).unwrap();
+        employee.record_payment(1, PaymentType::Regular).unwrap();
+        assert_eq!(employee.last_paid_period, 1);
+        assert_eq!(employee.last_off_cycle_index, 1);
+    }
+
+    fn funded_run(expected_count: u32) -> PayrollRun {
+        PayrollRun {
+            period: 1,
+            status: RunStatus::Funded,
+            expected_count,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn run_completes_after_expected_regular_payments() {
+        let mut run = funded_run(2);
+        run.record_payments(1, PaymentType::Regular, 1, 10).unwrap();
+        assert_eq!(run.status, RunStatus::Executing);
+        run.record_payments(1, PaymentType::Regular, 1, 20).unwrap();
+        assert_eq!(run.status, RunStatus::Completed);
+        assert_eq!(run.completed_at, 20);
+
+        assert_eq!(
+            run.record_payments(1, PaymentType::Regular, 1, 30).unwrap_err(),
+            PayrollError::InvalidRunStatus.into()
+        );
+    }
+
+    #[test]
+    fn off_cycle_payments_do_not_complete_run() {
+        let mut run = funded_run(1);
+        run.record_payments(1, PaymentType::OffCycle { index: 1 }, 1, 10).unwrap();
+        run.record_payments(1, PaymentType::OffCycle { index: 2 }, 1, 10).unwrap();
+        assert_eq!(run.status, RunStatus::Funded);
+        assert_eq!(run.processed_count, 0);
+        assert_eq!(run.off_cycle_count, 2);
+
+        assert_eq!(
+            run.record_payments(1, PaymentType::Regular, 2, 10).unwrap_err(),
+            PayrollError::RunCountExceeded.into()
+        );
+        assert_eq!(
+            run.record_payments(2, PaymentType::Regular, 1, 10).unwrap_err(),
+            PayrollError::RunPeriodMismatch.into()
+        );
+    }
+
+    #[test]
+    fn cancelled_run_keeps_its_payments() {
+        let mut run = funded_run(3);
+        run.record_payments(1, PaymentType::Regular, 1, 10).unwrap();
+        run.cancel(20).unwrap();
+        assert_eq!(run.status, RunStatus::Cancelled);
+        assert_eq!(run.cancelled_at, 20);
+        assert_eq!(run.processed_count, 1);
+
+        assert_eq!(run.cancel(30).unwrap_err(), PayrollError::InvalidRunStatus.into());
+        assert_eq!(
+            run.record_payments(1, PaymentType::Regular, 1, 30).unwrap_err(),
+            PayrollError::InvalidRunStatus.into()
+        );
+    }
+
+    #[test]
+    fn off_cycle_payments_cannot_spend_reserved_funds() {
+        let payroll = Payroll {
+            reserved_amount: 700,
+            ..Default::default()
+        };
+        assert_eq!(payroll.available_for(PaymentType::Regular, 1_000), 1_000);
+        assert_eq!(payroll.available_for(PaymentType::OffCycle { index: 1 }, 1_000), 300);
+        assert_eq!(payroll.available_for(PaymentType::OffCycle { index: 1 }, 500), 0);
+    }
+
+    fn policy(approvers: &[Pubkey], threshold: u8) -> ApprovalPolicy {
+        ApprovalPolicy {
+            approvers: approvers.to_vec(),
+            threshold,
+        }
+    }
+
+    #[test]
+    fn approvals_count_only_current_approvers() {
+        let approvers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut proposal = PaymentProposal::default();
+        for approver in &approvers {
+            proposal.approve(&policy(&approvers, 2), *approver).unwrap();
+        }
+
+        // Rotate out the first approver: their approval stops counting and
+        // is dropped on the next approval
+        let replacement = Pubkey::new_unique();
+        let rotated = policy(&[approvers[1], approvers[2], replacement], 2);
+        assert_eq!(rotated.count_approvals(&proposal.approvals), 2);
+        assert_eq!(proposal.approve(&rotated, replacement).unwrap(), 3);
+        assert!(!proposal.approvals.contains(&approvers[0]));
+    }
+
+    #[test]
+    fn approve_rejects_outsiders_and_repeats() {
+        let approver = Pubkey::new_unique();
+        let policy = policy(&[approver], 1);
+        let mut proposal = PaymentProposal::default();
+
+        assert_eq!(
+            proposal.approve(&policy, Pubkey::new_unique()).unwrap_err(),
+            PayrollError::NotApprover.into()
+        );
+        proposal.approve(&policy, approver).unwrap();
+        assert_eq!(
+            proposal.approve(&policy, approver).unwrap_err(),
+            PayrollError::AlreadyApproved.into()
+        );
+    }
+
+    #[test]
+    fn approvals_never_exceed_capacity() {
+        let approvers: Vec<Pubkey> =
+            (0..=ApprovalPolicy::MAX_APPROVERS).map(|_| Pubkey::new_unique()).collect();
+        let policy = policy(&approvers, 1);
+        let mut proposal = PaymentProposal::default();
+        for approver in &approvers[..ApprovalPolicy::MAX_APPROVERS] {
+            proposal.approve(&policy, *approver).unwrap();
+        }
+
+        assert_eq!(
+            proposal.approve(&policy, approvers[ApprovalPolicy::MAX_APPROVERS]).unwrap_err(),
+            PayrollError::ApprovalsFull.into()
+        );
+    }
+}